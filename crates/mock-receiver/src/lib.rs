@@ -0,0 +1,35 @@
+//! Fungible token receiver for tests.
+//!
+//! `msg` is the amount of tokens to return to the sender, "panic" lets the call fail.
+
+use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::U128,
+    near_bindgen, AccountId, PromiseOrValue,
+};
+
+#[near_bindgen]
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct Contract {}
+
+#[near_bindgen]
+impl FungibleTokenReceiver for Contract {
+    #[allow(unused_variables)]
+    fn ft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        amount: U128,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
+        if msg == "panic" {
+            env::panic_str("ft_on_transfer failed");
+        }
+        let refund: u128 = msg
+            .parse()
+            .unwrap_or_else(|_| env::panic_str("Invalid msg"));
+        PromiseOrValue::Value(refund.into())
+    }
+}
@@ -0,0 +1,74 @@
+use super::AuroraInit;
+use crate::view;
+use aurora_sdk_integration_tests::{
+    aurora_engine, aurora_engine_sdk::types::near_account_to_evm_address,
+    aurora_engine_types::types::Wei,
+};
+use near_workspaces::AccountId;
+use primitive_types::U256;
+
+#[tokio::test]
+async fn test_migrate_to_eth_implicit() -> anyhow::Result<()> {
+    let mint_amount = 10_000;
+    let AuroraInit {
+        engine,
+        aurora_wnear,
+        shitzu_erc20,
+        owner,
+        contract,
+        sol_contract,
+        ..
+    } = super::initialize_aurora(mint_amount, None).await?;
+    let owner_address = near_account_to_evm_address(owner.id().as_bytes());
+
+    engine
+        .mint_wnear(
+            &aurora_wnear,
+            sol_contract.address,
+            10_000_000_000_000_000_000_000_000,
+        )
+        .await?;
+    engine
+        .mint_account(
+            owner_address,
+            0,
+            Wei::new_u128(50_000_000_000_000_000_000_000),
+        )
+        .await?;
+    super::approve_wnear(&engine, &owner, &sol_contract).await?;
+    let result = engine
+        .call_evm_contract_with(
+            &owner,
+            shitzu_erc20.address,
+            shitzu_erc20.create_approve_call_bytes(sol_contract.address, U256::MAX),
+            Wei::zero(),
+        )
+        .await?;
+    aurora_engine::unwrap_success(result.status)?;
+
+    // the sender does not need to know a named account
+    let eth_implicit_id: AccountId = format!("0x{}", owner_address.encode()).parse()?;
+    super::migrate_to_eth_implicit(&engine, &owner, &sol_contract, 1_000).await?;
+    assert_eq!(
+        view::ft_balance_of(&contract, &eth_implicit_id).await?.0,
+        1_000
+    );
+
+    // named accounts are still supported
+    super::migrate(
+        &engine,
+        &owner,
+        &sol_contract,
+        owner.id().to_string(),
+        2_000,
+    )
+    .await?;
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 2_000);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 3_000);
+    let shitzuv1_balance = engine
+        .erc20_balance_of(&shitzu_erc20, owner_address)
+        .await?;
+    assert_eq!(shitzuv1_balance.as_u128(), mint_amount - 3_000);
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use super::AuroraInit;
+use crate::{call, event, view};
+use aurora_sdk_integration_tests::{
+    aurora_engine, aurora_engine_sdk::types::near_account_to_evm_address,
+    aurora_engine_types::types::Wei,
+};
+use primitive_types::U256;
+use token::MintArgs;
+
+/// Account IDs that would reshape the arguments of the JSON encoded `mint` call
+/// or that are not valid NEAR account IDs.
+fn hostile_account_ids(account_id: &str) -> Vec<String> {
+    vec![
+        format!("{}\", \"amount\": \"1000000", account_id),
+        format!("{}\"}}, {{\"account_id\": \"{}", account_id, account_id),
+        format!("{}\\", account_id),
+        format!("{} ", account_id),
+        account_id.to_uppercase(),
+        String::new(),
+    ]
+}
+
+#[tokio::test]
+async fn test_migrate_hostile_account_ids() -> anyhow::Result<()> {
+    let mint_amount = 10_000;
+    let AuroraInit {
+        engine,
+        aurora_wnear,
+        shitzu_erc20,
+        owner,
+        contract,
+        sol_contract,
+        near_representative_id,
+        ..
+    } = super::initialize_aurora(mint_amount, None).await?;
+    let owner_address = near_account_to_evm_address(owner.id().as_bytes());
+
+    engine
+        .mint_wnear(
+            &aurora_wnear,
+            sol_contract.address,
+            10_000_000_000_000_000_000_000_000,
+        )
+        .await?;
+    engine
+        .mint_account(
+            owner_address,
+            0,
+            Wei::new_u128(50_000_000_000_000_000_000_000),
+        )
+        .await?;
+    super::approve_wnear(&engine, &owner, &sol_contract).await?;
+    let result = engine
+        .call_evm_contract_with(
+            &owner,
+            shitzu_erc20.address,
+            shitzu_erc20.create_approve_call_bytes(sol_contract.address, U256::MAX),
+            Wei::zero(),
+        )
+        .await?;
+    aurora_engine::unwrap_success(result.status)?;
+
+    // failed mints are refunded on Aurora
+    for account_id in hostile_account_ids(owner.id().as_str()) {
+        super::migrate(&engine, &owner, &sol_contract, account_id, 1_000).await?;
+        let shitzuv1_balance = engine
+            .erc20_balance_of(&shitzu_erc20, owner_address)
+            .await?;
+        assert_eq!(shitzuv1_balance.as_u128(), mint_amount);
+    }
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 0);
+
+    super::migrate(
+        &engine,
+        &owner,
+        &sol_contract,
+        owner.id().to_string(),
+        1_000,
+    )
+    .await?;
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 1_000);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 1_000);
+
+    // ids of failed mints are skipped by the migration contract
+    let migration_id = hostile_account_ids(owner.id().as_str()).len() as u64;
+    let minter_id: near_workspaces::AccountId = near_representative_id.parse()?;
+    assert!(!view::is_migration_processed(&contract, &minter_id, 0.into()).await?);
+    assert!(view::is_migration_processed(&contract, &minter_id, migration_id.into()).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_borsh() -> anyhow::Result<()> {
+    let (_, owner, contract, bridge) = super::initialize_token().await?;
+
+    for account_id in hostile_account_ids(owner.id().as_str()) {
+        let args = MintArgs {
+            account_id,
+            amount: 1_000,
+            migration_id: 0,
+            aurora_sender: [0xab; 20],
+        };
+        assert!(call::mint_borsh(&contract, &bridge, &args).await.is_err());
+    }
+
+    let args = MintArgs {
+        account_id: owner.id().to_string(),
+        amount: 1_000,
+        migration_id: 0,
+        aurora_sender: [0xab; 20],
+    };
+    assert!(call::mint_borsh(&contract, &owner, &args).await.is_err());
+    let res = call::mint_borsh(&contract, &bridge, &args).await?;
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 1_000);
+    assert!(view::is_migration_processed(&contract, bridge.id(), 0.into()).await?);
+    let migrate = event::parse_events(res.logs())?
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::ShitzuMigrate(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        migrate.aurora_sender,
+        Some(format!("0x{}", "ab".repeat(20)))
+    );
+    assert_eq!(migrate.migration_id.as_deref(), Some("0"));
+
+    // the same migration can not be minted twice
+    assert!(call::mint_borsh(&contract, &bridge, &args).await.is_err());
+
+    Ok(())
+}
@@ -16,6 +16,9 @@ use serde_json::json;
 use std::path::Path;
 use tokio::fs;
 
+mod eth_implicit;
+mod mint_borsh;
+
 pub struct AuroraInit {
     pub worker: Worker<Sandbox>,
     pub engine: AuroraEngine,
@@ -138,6 +141,21 @@ pub async fn initialize_contracts(
     Ok((worker, owner, contract))
 }
 
+/// Deploys and initializes the token with a bridge account as migrate address.
+pub async fn initialize_token() -> anyhow::Result<(Worker<Sandbox>, Account, Contract, Account)> {
+    let (worker, owner, contract) = initialize_contracts(None).await?;
+    let bridge = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json((owner.id(), bridge.id()))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    Ok((worker, owner, contract, bridge))
+}
+
 async fn deploy_sol_contract(
     engine: &AuroraEngine,
     proxy_account: &Account,
@@ -159,10 +177,13 @@ async fn deploy_sol_contract(
             .unwrap();
     let constructor = forge::forge_build(
         contract_path,
-        &[format!(
-            "aurora-sdk/AuroraSdk.sol:AuroraSdk:0x{}",
-            aurora_sdk_lib.encode()
-        )],
+        &[
+            format!(
+                "aurora-sdk/AuroraSdk.sol:AuroraSdk:0x{}",
+                aurora_sdk_lib.encode()
+            ),
+            format!("aurora-sdk/Codec.sol:Codec:0x{}", codec_lib.encode()),
+        ],
         &["out", "ShitzuMigrate.sol", "ShitzuMigrate.json"],
     )
     .await
@@ -222,3 +243,25 @@ pub async fn migrate<T: Into<Uint>>(
     aurora_engine::unwrap_success(result.status)?;
     Ok(())
 }
+
+pub async fn migrate_to_eth_implicit<T: Into<Uint>>(
+    engine: &AuroraEngine,
+    account: &Account,
+    sol_contract: &DeployedContract,
+    amount: T,
+) -> anyhow::Result<()> {
+    println!("migrate to ETH-implicit account");
+    let result = engine
+        .call_evm_contract_with(
+            account,
+            sol_contract.address,
+            ContractInput(sol_contract.create_call_method_bytes_with_args(
+                "migrateToEthImplicit",
+                &[ethabi::Token::Uint(amount.into())],
+            )),
+            Wei::zero(),
+        )
+        .await?;
+    aurora_engine::unwrap_success(result.status)?;
+    Ok(())
+}
@@ -8,7 +8,11 @@ use aurora_sdk_integration_tests::{
     aurora_engine, aurora_engine_sdk::types::near_account_to_evm_address,
     aurora_engine_types::types::Wei,
 };
-use near_sdk::{env, json_types::U128, NearToken};
+use near_sdk::{
+    env,
+    json_types::{Base64VecU8, U128, U64},
+    NearToken,
+};
 use near_workspaces::types::{KeyType, SecretKey};
 use primitive_types::U256;
 
@@ -22,6 +26,7 @@ async fn test_migration_success() -> anyhow::Result<()> {
         owner,
         contract,
         sol_contract,
+        near_representative_id,
         ..
     } = aurora::initialize_aurora(mint_amount, None).await?;
     let owner_address = near_account_to_evm_address(owner.id().as_bytes());
@@ -92,10 +97,14 @@ async fn test_migration_success() -> anyhow::Result<()> {
         .await?;
     assert_eq!(shitzuv1_balance.as_u128(), mint_amount - 1_001);
 
+    let minter_id: near_workspaces::AccountId = near_representative_id.parse()?;
+    assert!(view::is_migration_processed(&contract, &minter_id, 0.into()).await?);
+    assert!(view::is_migration_processed(&contract, &minter_id, 1.into()).await?);
+    assert!(!view::is_migration_processed(&contract, &minter_id, 2.into()).await?);
+
     Ok(())
 }
 
-// in order to run this test set the timestamp in the contract crates/token/src/lib.rs accordingly and rebuild
 #[tokio::test]
 async fn test_migration_closed() -> anyhow::Result<()> {
     let mint_amount = 10_000;
@@ -157,9 +166,12 @@ async fn test_migration_closed() -> anyhow::Result<()> {
         .await?;
     assert_eq!(shitzuv1_balance.as_u128(), mint_amount - 1);
 
-    while worker.view_block().await?.header().timestamp_nanosec() < 1717165435788748539 {
+    let closes_at = worker.view_block().await?.header().timestamp_nanosec() + 10_000_000_000;
+    call::set_migration_window(&contract, &owner, 0.into(), Some(closes_at.into())).await?;
+    while worker.view_block().await?.header().timestamp_nanosec() < closes_at {
         worker.fast_forward(100).await?;
     }
+    assert!(!view::migration_status(&contract).await?.is_open);
 
     aurora::migrate(
         &engine,
@@ -181,6 +193,30 @@ async fn test_migration_closed() -> anyhow::Result<()> {
         .await?;
     assert_eq!(shitzuv1_balance.as_u128(), mint_amount - 1);
 
+    // a closed migration can only be reopened explicitly
+    assert!(
+        call::set_migration_window(&contract, &owner, 0.into(), None)
+            .await
+            .is_err()
+    );
+    call::reopen_migration(&contract, &owner, None).await?;
+    assert!(view::migration_status(&contract).await?.is_open);
+
+    aurora::migrate(
+        &engine,
+        &owner,
+        &sol_contract,
+        owner.id().to_string(),
+        1_000,
+    )
+    .await?;
+    let balance: U128 = contract
+        .view("ft_balance_of")
+        .args_json((owner.id(),))
+        .await?
+        .json()?;
+    assert_eq!(balance.0, 1_001);
+
     Ok(())
 }
 
@@ -244,12 +280,7 @@ async fn test_mint_check_predecessor() -> anyhow::Result<()> {
     } = aurora::initialize_aurora(mint_amount, None).await?;
     let owner_address = near_account_to_evm_address(owner.id().as_bytes());
 
-    let res = owner
-        .call(contract.id(), "mint")
-        .args_json((owner.id(), 1_000))
-        .transact()
-        .await?
-        .into_result();
+    let res = call::mint(&contract, &owner, owner.id(), 1_000.into()).await;
     assert!(res.is_err());
 
     let balance: U128 = contract
@@ -366,3 +397,1766 @@ async fn test_upgrade_contract_via_dao() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_update_metadata() -> anyhow::Result<()> {
+    let (worker, owner, contract, _) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+
+    let metadata = view::ft_metadata(&contract).await?;
+    assert_eq!(metadata.symbol, "SHITZU");
+    assert_eq!(metadata.decimals, 18);
+    assert!(metadata.icon.is_some());
+    assert!(metadata.reference.is_none());
+
+    let icon = "data:image/svg+xml,<svg></svg>".to_string();
+    let reference = "https://shitzuapes.xyz/token.json".to_string();
+
+    let res = call::set_ft_metadata(
+        &contract,
+        &user,
+        Some(icon.clone()),
+        Some(reference.clone()),
+        Some(Base64VecU8(vec![1; 32])),
+    )
+    .await;
+    assert!(res.is_err());
+
+    let res = call::set_ft_metadata(
+        &contract,
+        &owner,
+        Some(icon.clone()),
+        Some(reference.clone()),
+        Some(Base64VecU8(vec![1; 31])),
+    )
+    .await;
+    assert!(res.is_err());
+
+    let res = call::set_ft_metadata(
+        &contract,
+        &owner,
+        Some("https://shitzuapes.xyz/icon.png".to_string()),
+        None,
+        None,
+    )
+    .await;
+    assert!(res.is_err());
+
+    let res = call::set_ft_metadata(
+        &contract,
+        &owner,
+        Some(icon.clone()),
+        Some(reference.clone()),
+        Some(Base64VecU8(vec![1; 32])),
+    )
+    .await?;
+    let events = event::parse_events(res.logs())?;
+    let update = events
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::FtMetadataUpdate(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(update.reference, Some(reference.clone()));
+    assert_eq!(
+        update.reference_hash.as_deref(),
+        serde_json::to_value(Base64VecU8(vec![1; 32]))?.as_str()
+    );
+
+    let metadata = view::ft_metadata(&contract).await?;
+    assert_eq!(metadata.symbol, "SHITZU");
+    assert_eq!(metadata.icon, Some(icon));
+    assert_eq!(metadata.reference, Some(reference));
+    assert_eq!(metadata.reference_hash.unwrap().0, vec![1; 32]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_roles() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let admin = worker.dev_create_account().await?;
+    let user = worker.dev_create_account().await?;
+
+    assert!(view::has_role(&contract, "minter", bridge.id()).await?);
+    assert!(!view::has_role(&contract, "minter", owner.id()).await?);
+    assert!(view::has_role(&contract, "upgrader", owner.id()).await?);
+    assert!(!view::has_role(&contract, "minter", user.id()).await?);
+    assert_eq!(
+        view::role_members(&contract, "minter").await?,
+        vec![bridge.id().clone()]
+    );
+
+    call::mint(&contract, &bridge, bridge.id(), 100.into()).await?;
+    assert!(call::mint(&contract, &user, user.id(), 100.into())
+        .await
+        .is_err());
+
+    // only the owner can hand out the admin role
+    assert!(call::grant_role(&contract, &user, "admin", user.id())
+        .await
+        .is_err());
+    call::grant_role(&contract, &owner, "admin", admin.id()).await?;
+    assert!(call::grant_role(&contract, &admin, "admin", user.id())
+        .await
+        .is_err());
+
+    // only the owner can hand out the minter role
+    assert!(call::grant_role(&contract, &admin, "minter", user.id())
+        .await
+        .is_err());
+    call::grant_role(&contract, &owner, "minter", user.id()).await?;
+    call::mint(&contract, &user, user.id(), 100.into()).await?;
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 100);
+
+    call::grant_role(&contract, &admin, "pauser", user.id()).await?;
+    assert!(view::has_role(&contract, "pauser", user.id()).await?);
+    call::revoke_role(&contract, &admin, "pauser", user.id()).await?;
+    assert!(!view::has_role(&contract, "pauser", user.id()).await?);
+
+    call::revoke_role(&contract, &owner, "minter", bridge.id()).await?;
+    assert!(call::mint(&contract, &bridge, bridge.id(), 100.into())
+        .await
+        .is_err());
+
+    call::renounce_role(&contract, &user, "minter").await?;
+    assert!(!view::has_role(&contract, "minter", user.id()).await?);
+    assert!(call::mint(&contract, &user, user.id(), 100.into())
+        .await
+        .is_err());
+    assert!(call::renounce_role(&contract, &user, "minter")
+        .await
+        .is_err());
+
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 200);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ownership_transfer() -> anyhow::Result<()> {
+    let (worker, owner, contract, _) = aurora::initialize_token().await?;
+    let new_owner = worker.dev_create_account().await?;
+    let user = worker.dev_create_account().await?;
+
+    assert!(call::propose_owner(&contract, &user, user.id(), None)
+        .await
+        .is_err());
+    assert!(call::accept_ownership(&contract, &new_owner).await.is_err());
+
+    // an expired proposal can not be accepted
+    let now = worker.view_block().await?.timestamp();
+    call::propose_owner(&contract, &owner, new_owner.id(), Some((now + 1).into())).await?;
+    worker.fast_forward(10).await?;
+    assert!(call::accept_ownership(&contract, &new_owner).await.is_err());
+
+    call::propose_owner(&contract, &owner, new_owner.id(), None).await?;
+    let pending_owner = view::pending_owner(&contract).await?.unwrap();
+    assert_eq!(&pending_owner.account_id, new_owner.id());
+    assert!(pending_owner.expires_at.is_none());
+
+    call::cancel_ownership_transfer(&contract, &owner).await?;
+    assert!(view::pending_owner(&contract).await?.is_none());
+    assert!(call::accept_ownership(&contract, &new_owner).await.is_err());
+
+    call::propose_owner(&contract, &owner, new_owner.id(), None).await?;
+    assert!(call::accept_ownership(&contract, &user).await.is_err());
+    call::accept_ownership(&contract, &new_owner).await?;
+    assert_eq!(&view::owner(&contract).await?, new_owner.id());
+    assert!(view::pending_owner(&contract).await?.is_none());
+
+    assert!(call::propose_owner(&contract, &owner, user.id(), None)
+        .await
+        .is_err());
+    call::grant_role(&contract, &new_owner, "admin", user.id()).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pause() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let guardian = worker.dev_create_account().await?;
+    let user = worker.dev_create_account().await?;
+    call::grant_role(&contract, &owner, "pauser", guardian.id()).await?;
+    call::storage_deposit(&contract, &owner, None, Some(true), None).await?;
+    call::mint(&contract, &bridge, user.id(), 1_000.into()).await?;
+
+    assert!(call::pause(&contract, &user, &["transfers"]).await.is_err());
+    call::pause(&contract, &guardian, &["transfers"]).await?;
+    let status = view::pause_status(&contract).await?;
+    assert!(status.transfers);
+    assert!(!status.mint);
+    assert!(!status.storage);
+
+    assert!(call::ft_transfer(&user, contract.id(), owner.id(), 100)
+        .await
+        .is_err());
+    call::mint(&contract, &bridge, user.id(), 1_000.into()).await?;
+
+    // the guardian can only pause
+    assert!(call::unpause(&contract, &guardian, &["transfers"])
+        .await
+        .is_err());
+    call::unpause(&contract, &owner, &["transfers"]).await?;
+    call::ft_transfer(&user, contract.id(), owner.id(), 100).await?;
+
+    call::pause(&contract, &guardian, &["mint", "storage"]).await?;
+    let status = view::pause_status(&contract).await?;
+    assert!(!status.transfers);
+    assert!(status.mint);
+    assert!(status.storage);
+    assert!(call::mint(&contract, &bridge, user.id(), 1_000.into())
+        .await
+        .is_err());
+    let res = user
+        .call(contract.id(), "storage_unregister")
+        .args_json((true,))
+        .deposit(NearToken::from_yoctonear(1))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result();
+    assert!(res.is_err());
+
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_900);
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migration_accounting() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user_0 = worker.dev_create_account().await?;
+    let user_1 = worker.dev_create_account().await?;
+
+    call::mint(&contract, &bridge, user_0.id(), 100.into()).await?;
+    call::mint(&contract, &bridge, user_0.id(), 200.into()).await?;
+    call::mint(&contract, &bridge, user_1.id(), 300.into()).await?;
+
+    let stats = view::migration_stats(&contract).await?;
+    assert_eq!(stats.total_migrated.0, 600);
+    assert_eq!(stats.migration_count.0, 3);
+    assert!(stats.max_migratable_supply.is_none());
+    assert_eq!(
+        view::migrated_balance_of(&contract, user_0.id()).await?.0,
+        300
+    );
+    assert_eq!(
+        view::migrated_balance_of(&contract, user_1.id()).await?.0,
+        300
+    );
+    assert_eq!(view::migrated_balance_of(&contract, owner.id()).await?.0, 0);
+
+    assert!(
+        call::set_max_migratable_supply(&contract, &user_0, Some(1_000.into()))
+            .await
+            .is_err()
+    );
+    assert!(
+        call::set_max_migratable_supply(&contract, &owner, Some(500.into()))
+            .await
+            .is_err()
+    );
+    call::set_max_migratable_supply(&contract, &owner, Some(1_000.into())).await?;
+
+    call::mint(&contract, &bridge, user_1.id(), 400.into()).await?;
+    assert!(call::mint(&contract, &bridge, user_1.id(), 1.into())
+        .await
+        .is_err());
+
+    let stats = view::migration_stats(&contract).await?;
+    assert_eq!(stats.total_migrated.0, 1_000);
+    assert_eq!(stats.migration_count.0, 4);
+    assert_eq!(stats.max_migratable_supply.unwrap().0, 1_000);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 1_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_migration_id() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+
+    assert!(!view::is_migration_processed(&contract, bridge.id(), 1.into()).await?);
+    call::mint_migration(
+        &contract,
+        &bridge,
+        owner.id(),
+        100.into(),
+        Some(1.into()),
+        None,
+    )
+    .await?;
+    assert!(view::is_migration_processed(&contract, bridge.id(), 1.into()).await?);
+
+    // a replayed migration must not mint twice
+    assert!(call::mint_migration(
+        &contract,
+        &bridge,
+        owner.id(),
+        100.into(),
+        Some(1.into()),
+        None
+    )
+    .await
+    .is_err());
+
+    call::mint_migration(
+        &contract,
+        &bridge,
+        owner.id(),
+        100.into(),
+        Some(0.into()),
+        None,
+    )
+    .await?;
+    call::mint_migration(
+        &contract,
+        &bridge,
+        owner.id(),
+        100.into(),
+        Some(129.into()),
+        None,
+    )
+    .await?;
+    call::mint_migration(
+        &contract,
+        &bridge,
+        owner.id(),
+        100.into(),
+        Some(u64::MAX.into()),
+        None,
+    )
+    .await?;
+    assert!(view::is_migration_processed(&contract, bridge.id(), 0.into()).await?);
+    assert!(view::is_migration_processed(&contract, bridge.id(), 129.into()).await?);
+    assert!(view::is_migration_processed(&contract, bridge.id(), u64::MAX.into()).await?);
+    assert!(!view::is_migration_processed(&contract, bridge.id(), 2.into()).await?);
+    assert!(!view::is_migration_processed(&contract, bridge.id(), 128.into()).await?);
+
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 400);
+
+    // a redeployed migration contract counts its ids from the start again
+    let new_bridge = worker.dev_create_account().await?;
+    call::set_migrate_address(&contract, &owner, new_bridge.id()).await?;
+    assert!(!view::is_migration_processed(&contract, new_bridge.id(), 1.into()).await?);
+    call::mint_migration(
+        &contract,
+        &new_bridge,
+        owner.id(),
+        100.into(),
+        Some(1.into()),
+        None,
+    )
+    .await?;
+    assert!(view::is_migration_processed(&contract, new_bridge.id(), 1.into()).await?);
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 500);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_migrate_event() -> anyhow::Result<()> {
+    let (_, owner, contract, bridge) = aurora::initialize_token().await?;
+
+    let res = call::mint_migration(
+        &contract,
+        &bridge,
+        owner.id(),
+        100.into(),
+        Some(7.into()),
+        Some("0x1F9840a85d5aF5bf1D1762F925BDADdC4201F984"),
+    )
+    .await?;
+    let events = event::parse_events(res.logs())?;
+    let migrate = events
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::ShitzuMigrate(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(&migrate.account_id, owner.id().as_str());
+    assert_eq!(migrate.amount, "100");
+    assert_eq!(
+        migrate.aurora_sender.as_deref(),
+        Some("0x1f9840a85d5af5bf1d1762f925bdaddc4201f984")
+    );
+    assert_eq!(migrate.migration_id.as_deref(), Some("7"));
+
+    for aurora_sender in [
+        "1f9840a85d5af5bf1d1762f925bdaddc4201f984",
+        "0x1f9840a85d5af5bf1d1762f925bdaddc4201f9",
+        "0x1f9840a85d5af5bf1d1762f925bdaddc4201f9zz",
+        "0x+f+840a85d5af5bf1d1762f925bdaddc4201f984",
+        "0x1f9840a85d5af5bf1d1762f925bdaddc4201f9é",
+    ] {
+        assert!(call::mint_migration(
+            &contract,
+            &bridge,
+            owner.id(),
+            100.into(),
+            None,
+            Some(aurora_sender),
+        )
+        .await
+        .is_err());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_migrate_address() -> anyhow::Result<()> {
+    let mint_amount = 10_000;
+    let aurora::AuroraInit {
+        worker,
+        engine,
+        owner,
+        contract,
+        near_representative_id,
+        ..
+    } = aurora::initialize_aurora(mint_amount, None).await?;
+    let bridge = worker.dev_create_account().await?;
+
+    assert_eq!(
+        view::migrate_address(&contract).await?.as_str(),
+        near_representative_id
+    );
+
+    assert!(call::set_migrate_address(&contract, &bridge, bridge.id())
+        .await
+        .is_err());
+    call::set_migrate_address(&contract, &owner, bridge.id()).await?;
+    assert_eq!(&view::migrate_address(&contract).await?, bridge.id());
+    assert!(view::has_role(&contract, "minter", bridge.id()).await?);
+    assert!(!view::has_role(&contract, "minter", &near_representative_id.parse()?).await?);
+    call::mint(&contract, &bridge, owner.id(), 100.into()).await?;
+
+    // derive the account of the migration contract from its EVM address
+    let evm_address = format!("0x{}", near_representative_id.split('.').next().unwrap());
+    call::set_migrate_evm_address(&contract, &owner, &evm_address, engine.inner.id()).await?;
+    assert_eq!(
+        view::migrate_address(&contract).await?.as_str(),
+        near_representative_id
+    );
+    assert!(!view::has_role(&contract, "minter", bridge.id()).await?);
+    assert!(call::mint(&contract, &bridge, owner.id(), 100.into())
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_minter_quota() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+
+    // the migrate address is registered without limits
+    let minter = view::minter(&contract, bridge.id()).await?.unwrap();
+    assert_eq!(minter.quota, None);
+    assert!(minter.enabled);
+
+    // only the owner can manage minters
+    assert!(
+        call::add_minter(&contract, &user, user.id(), Some(150.into()), None)
+            .await
+            .is_err()
+    );
+    call::add_minter(&contract, &owner, user.id(), Some(150.into()), None).await?;
+    assert!(view::has_role(&contract, "minter", user.id()).await?);
+    assert_eq!(view::minters(&contract).await?.len(), 2);
+
+    call::mint(&contract, &user, user.id(), 100.into()).await?;
+    assert!(call::mint(&contract, &user, user.id(), 51.into())
+        .await
+        .is_err());
+    call::mint(&contract, &user, user.id(), 50.into()).await?;
+    let minter = view::minter(&contract, user.id()).await?.unwrap();
+    assert_eq!(minter.minted.0, 150);
+
+    // quota can not be lowered below the minted amount
+    assert!(
+        call::update_minter(&contract, &owner, user.id(), Some(100.into()), None)
+            .await
+            .is_err()
+    );
+    call::update_minter(&contract, &owner, user.id(), Some(200.into()), None).await?;
+
+    call::set_minter_enabled(&contract, &owner, user.id(), false).await?;
+    assert!(call::mint(&contract, &user, user.id(), 10.into())
+        .await
+        .is_err());
+    call::set_minter_enabled(&contract, &owner, user.id(), true).await?;
+    call::mint(&contract, &user, user.id(), 10.into()).await?;
+
+    // expired minters can not mint anymore
+    let expires_at = worker.view_block().await?.timestamp() + 1_000_000_000;
+    call::update_minter(
+        &contract,
+        &owner,
+        user.id(),
+        Some(200.into()),
+        Some(expires_at.into()),
+    )
+    .await?;
+    while worker.view_block().await?.timestamp() < expires_at {
+        worker.fast_forward(10).await?;
+    }
+    assert!(call::mint(&contract, &user, user.id(), 10.into())
+        .await
+        .is_err());
+
+    call::remove_minter(&contract, &owner, user.id()).await?;
+    assert!(view::minter(&contract, user.id()).await?.is_none());
+    assert!(!view::has_role(&contract, "minter", user.id()).await?);
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 160);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_rate_limit() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+
+    let window = 10_000_000_000;
+    assert!(
+        call::set_mint_rate_limit(&contract, &bridge, window.into(), Some(1_000.into()))
+            .await
+            .is_err()
+    );
+    call::set_mint_rate_limit(&contract, &owner, window.into(), Some(1_000.into())).await?;
+
+    call::mint(&contract, &bridge, bridge.id(), 600.into()).await?;
+    call::mint(&contract, &bridge, bridge.id(), 400.into()).await?;
+    let rate_limit = view::mint_rate_limit(&contract).await?;
+    assert_eq!(rate_limit.limit, Some(1_000.into()));
+    assert_eq!(rate_limit.window_usage.0, 1_000);
+
+    // invalid mints fail without tripping the circuit breaker
+    call::set_minter_enabled(&contract, &owner, bridge.id(), false).await?;
+    assert!(
+        call::mint(&contract, &bridge, bridge.id(), 1_000_000.into())
+            .await
+            .is_err()
+    );
+    assert!(!view::pause_status(&contract).await?.mint);
+    call::set_minter_enabled(&contract, &owner, bridge.id(), true).await?;
+
+    // exceeding the limit fails the mint and trips the circuit breaker
+    assert!(call::mint(&contract, &bridge, bridge.id(), 1.into())
+        .await
+        .is_err());
+    assert!(view::pause_status(&contract).await?.mint);
+    assert_eq!(
+        view::mint_rate_limit(&contract).await?.window_usage.0,
+        1_000
+    );
+    assert_eq!(view::ft_balance_of(&contract, bridge.id()).await?.0, 1_000);
+
+    // minting stays paused even after the window has passed
+    let window_end = rate_limit.window_start.0 + window;
+    while worker.view_block().await?.timestamp() < window_end {
+        worker.fast_forward(10).await?;
+    }
+    assert_eq!(view::mint_rate_limit(&contract).await?.window_usage.0, 0);
+    assert!(call::mint(&contract, &bridge, bridge.id(), 1.into())
+        .await
+        .is_err());
+
+    call::unpause(&contract, &owner, &["mint"]).await?;
+    call::mint(&contract, &bridge, bridge.id(), 1.into()).await?;
+    assert_eq!(view::ft_balance_of(&contract, bridge.id()).await?.0, 1_001);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_finalize_migration() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let treasury = worker.dev_create_account().await?;
+
+    call::mint(&contract, &bridge, bridge.id(), 1_000.into()).await?;
+    call::set_max_migratable_supply(&contract, &owner, Some(10_000.into())).await?;
+
+    // the migration has to be over before it can be finalized
+    assert!(call::finalize_migration(&contract, &owner, None, None)
+        .await
+        .is_err());
+    let closes_at = worker.view_block().await?.timestamp() + 10_000_000_000;
+    call::set_migration_window(&contract, &owner, 0.into(), Some(closes_at.into())).await?;
+    while worker.view_block().await?.timestamp() < closes_at {
+        worker.fast_forward(100).await?;
+    }
+
+    assert!(
+        call::finalize_migration(&contract, &bridge, Some(treasury.id()), Some(9_000.into()))
+            .await
+            .is_err()
+    );
+    // the swept amount is limited by the max migratable supply
+    assert!(
+        call::finalize_migration(&contract, &owner, Some(treasury.id()), Some(9_001.into()))
+            .await
+            .is_err()
+    );
+    call::finalize_migration(&contract, &owner, Some(treasury.id()), Some(9_000.into())).await?;
+    assert!(view::migration_status(&contract).await?.is_finalized);
+    assert_eq!(
+        view::ft_balance_of(&contract, treasury.id()).await?.0,
+        9_000
+    );
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 10_000);
+    let stats = view::migration_stats(&contract).await?;
+    assert_eq!(stats.total_migrated.0, 10_000);
+    assert_eq!(stats.migration_count.0, 1);
+
+    // minting is disabled forever
+    assert!(call::finalize_migration(&contract, &owner, None, None)
+        .await
+        .is_err());
+    assert!(call::reopen_migration(&contract, &owner, None)
+        .await
+        .is_err());
+    assert!(call::mint(&contract, &bridge, bridge.id(), 1.into())
+        .await
+        .is_err());
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 10_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_storage_refunds() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user_0 = worker.dev_create_account().await?;
+    let user_1 = worker.dev_create_account().await?;
+    let user_2 = worker.dev_create_account().await?;
+    let min_balance = view::storage_balance_bounds(&contract).await?.min;
+    // the minimum covers the token account and the payer entry of 177 bytes
+    assert!(min_balance > NearToken::from_yoctonear(177 * 10u128.pow(19)));
+
+    // registrations by mint are sponsored by the contract
+    call::mint(&contract, &bridge, user_0.id(), 100.into()).await?;
+    let sponsorship = view::storage_sponsorship(&contract).await?;
+    assert_eq!(sponsorship.sponsored_count.0, 1);
+    assert_eq!(sponsorship.sponsored_amount, min_balance);
+
+    // the deposit for sponsored registrations is withheld
+    let balance = user_0.view_account().await?.balance;
+    call::storage_unregister(&contract, &user_0, Some(true)).await?;
+    assert!(user_0.view_account().await?.balance < balance);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 0);
+
+    // the deposit is refunded to whoever paid for the registration
+    call::storage_deposit(&contract, &user_1, None, Some(true), None).await?;
+    call::storage_deposit(&contract, &owner, Some(user_2.id()), Some(true), None).await?;
+    call::mint(&contract, &bridge, user_2.id(), 100.into()).await?;
+
+    let balance = user_1.view_account().await?.balance;
+    call::storage_unregister(&contract, &user_1, None).await?;
+    assert!(user_1.view_account().await?.balance > balance);
+
+    let balance = owner.view_account().await?.balance;
+    assert!(call::storage_unregister(&contract, &user_2, None)
+        .await
+        .is_err());
+    call::storage_unregister(&contract, &user_2, Some(true)).await?;
+    assert_eq!(
+        owner.view_account().await?.balance,
+        balance.saturating_add(min_balance)
+    );
+
+    assert_eq!(
+        view::storage_sponsorship(&contract)
+            .await?
+            .sponsored_count
+            .0,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_storage_sponsorship_budget() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user_0 = worker.dev_create_account().await?;
+    let user_1 = worker.dev_create_account().await?;
+    let user_2 = worker.dev_create_account().await?;
+    let min_balance = view::storage_balance_bounds(&contract).await?.min;
+
+    assert!(
+        call::set_storage_sponsorship_budget(&contract, &bridge, Some(2.into()), None)
+            .await
+            .is_err()
+    );
+    call::set_storage_sponsorship_budget(
+        &contract,
+        &owner,
+        Some(2.into()),
+        Some(min_balance.saturating_mul(10)),
+    )
+    .await?;
+
+    call::mint(&contract, &bridge, user_0.id(), 100.into()).await?;
+    call::mint(&contract, &bridge, user_1.id(), 100.into()).await?;
+    let sponsorship = view::storage_sponsorship(&contract).await?;
+    assert_eq!(sponsorship.remaining_count, Some(0.into()));
+    assert_eq!(
+        sponsorship.remaining_amount,
+        Some(min_balance.saturating_mul(8))
+    );
+
+    // once the budget is used up, accounts have to be registered before minting
+    assert!(call::mint(&contract, &bridge, user_2.id(), 100.into())
+        .await
+        .is_err());
+    call::mint(&contract, &bridge, user_0.id(), 100.into()).await?;
+    call::storage_deposit(&contract, &user_2, None, Some(true), None).await?;
+    call::mint(&contract, &bridge, user_2.id(), 100.into()).await?;
+
+    assert_eq!(
+        view::storage_sponsorship(&contract)
+            .await?
+            .sponsored_count
+            .0,
+        2
+    );
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 400);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_call() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+    let receiver = worker
+        .dev_deploy(&fs::read("../../res/mock_receiver.wasm").await?)
+        .await?;
+
+    // the receiver has to be registered
+    assert!(call::mint_call(
+        &contract,
+        &bridge,
+        user.id(),
+        receiver.id(),
+        1_000.into(),
+        "0"
+    )
+    .await
+    .is_err());
+    call::storage_deposit(&contract, &owner, Some(receiver.id()), Some(true), None).await?;
+
+    assert!(call::mint_call(
+        &contract,
+        &user,
+        user.id(),
+        receiver.id(),
+        1_000.into(),
+        "0"
+    )
+    .await
+    .is_err());
+    call::mint_call(
+        &contract,
+        &bridge,
+        user.id(),
+        receiver.id(),
+        1_000.into(),
+        "0",
+    )
+    .await?;
+    assert_eq!(
+        view::ft_balance_of(&contract, receiver.id()).await?.0,
+        1_000
+    );
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 0);
+
+    // unused tokens end up with the migrating account
+    call::mint_call(
+        &contract,
+        &bridge,
+        user.id(),
+        receiver.id(),
+        1_000.into(),
+        "300",
+    )
+    .await?;
+    assert_eq!(
+        view::ft_balance_of(&contract, receiver.id()).await?.0,
+        1_700
+    );
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 300);
+
+    call::mint_call(
+        &contract,
+        &bridge,
+        user.id(),
+        receiver.id(),
+        1_000.into(),
+        "panic",
+    )
+    .await?;
+    assert_eq!(
+        view::ft_balance_of(&contract, receiver.id()).await?.0,
+        1_700
+    );
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_300);
+
+    // nothing is forwarded while transfers are paused
+    call::pause(&contract, &owner, &["transfers"]).await?;
+    assert!(call::mint_call(
+        &contract,
+        &bridge,
+        user.id(),
+        receiver.id(),
+        1_000.into(),
+        "0"
+    )
+    .await
+    .is_err());
+    call::unpause(&contract, &owner, &["transfers"]).await?;
+
+    assert_eq!(
+        view::migrated_balance_of(&contract, user.id()).await?.0,
+        3_000
+    );
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 3_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_legacy_token_swap() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+    let other = worker.dev_create_account().await?;
+
+    let legacy = worker
+        .dev_deploy(&fs::read("../../res/test_token.wasm").await?)
+        .await?;
+    legacy
+        .call("new")
+        .args_json(("SHITZUv1", "SHITZU", None::<String>, 18))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    for account_id in [user.id(), contract.id()] {
+        call::storage_deposit(&legacy, &owner, Some(account_id), Some(true), None).await?;
+    }
+    legacy
+        .call("mint")
+        .args_json((user.id(), "10000"))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // tokens that are not allow-listed are refunded unless the sender has registered them
+    call::ft_transfer_call(&user, legacy.id(), contract.id(), 1_000, "").await?;
+    assert_eq!(view::ft_balance_of(&legacy, user.id()).await?.0, 10_000);
+    assert!(view::foreign_balances(&contract).await?.is_empty());
+
+    let cost = view::foreign_token_storage_cost(&contract).await?;
+    call::register_foreign_token(&contract, &user, legacy.id(), cost).await?;
+    call::ft_transfer_call(&user, legacy.id(), contract.id(), 1_000, "").await?;
+    assert_eq!(view::ft_balance_of(&legacy, user.id()).await?.0, 9_000);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 0);
+    call::withdraw_foreign_token(&contract, &user, legacy.id(), user.id()).await?;
+    assert_eq!(view::ft_balance_of(&legacy, user.id()).await?.0, 10_000);
+
+    assert!(call::set_legacy_token(&contract, &user, legacy.id(), true)
+        .await
+        .is_err());
+    call::set_legacy_token(&contract, &owner, legacy.id(), true).await?;
+
+    call::ft_transfer_call(&user, legacy.id(), contract.id(), 1_000, "").await?;
+    call::ft_transfer_call(&user, legacy.id(), contract.id(), 500, other.id().as_str()).await?;
+    assert_eq!(view::ft_balance_of(&legacy, user.id()).await?.0, 8_500);
+    assert_eq!(view::ft_balance_of(&legacy, contract.id()).await?.0, 1_500);
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_000);
+    assert_eq!(view::ft_balance_of(&contract, other.id()).await?.0, 500);
+    assert_eq!(
+        view::migration_stats(&contract).await?.total_migrated.0,
+        1_500
+    );
+    let legacy_tokens = view::legacy_tokens(&contract).await?;
+    assert_eq!(&legacy_tokens[0].token_id, legacy.id());
+    assert_eq!(legacy_tokens[0].locked.0, 1_500);
+
+    // swaps are counted against the migration cap
+    call::set_max_migratable_supply(&contract, &owner, Some(2_000.into())).await?;
+    call::ft_transfer_call(&user, legacy.id(), contract.id(), 501, "").await?;
+    assert_eq!(view::ft_balance_of(&legacy, user.id()).await?.0, 8_500);
+    call::mint(&contract, &bridge, user.id(), 500.into()).await?;
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 2_000);
+    call::set_max_migratable_supply(&contract, &owner, None).await?;
+
+    // swaps are only possible while the migration is open
+    let closes_at = worker.view_block().await?.timestamp() + 10_000_000_000;
+    call::set_migration_window(&contract, &owner, 0.into(), Some(closes_at.into())).await?;
+    while worker.view_block().await?.timestamp() < closes_at {
+        worker.fast_forward(100).await?;
+    }
+    call::ft_transfer_call(&user, legacy.id(), contract.id(), 1_000, "").await?;
+    assert_eq!(view::ft_balance_of(&legacy, user.id()).await?.0, 8_500);
+
+    call::reopen_migration(&contract, &owner, None).await?;
+    call::set_legacy_token(&contract, &owner, legacy.id(), false).await?;
+    call::ft_transfer_call(&user, legacy.id(), contract.id(), 1_000, "").await?;
+    assert_eq!(view::ft_balance_of(&legacy, user.id()).await?.0, 8_500);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 2_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_minter_exchange_rate() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+
+    let rate = view::minter(&contract, bridge.id()).await?.unwrap().rate;
+    assert_eq!(
+        (rate.numerator.0, rate.denominator.0, rate.decimals),
+        (1, 1, 18)
+    );
+    assert!(
+        call::set_minter_rate(&contract, &bridge, bridge.id(), 1, 1, 6)
+            .await
+            .is_err()
+    );
+    assert!(
+        call::set_minter_rate(&contract, &owner, bridge.id(), 0, 1, 18)
+            .await
+            .is_err()
+    );
+    // the decimals adjustment has to fit into u128
+    assert!(
+        call::set_minter_rate(&contract, &owner, bridge.id(), 1, 1, 57)
+            .await
+            .is_err()
+    );
+
+    // legacy token with 6 decimals
+    call::set_minter_rate(&contract, &owner, bridge.id(), 1, 1, 6).await?;
+    let res = call::mint(&contract, &bridge, user.id(), 1.into()).await?;
+    assert_eq!(res.json::<U128>()?.0, 1_000_000_000_000);
+    assert!(call::mint(&contract, &bridge, user.id(), u128::MAX.into())
+        .await
+        .is_err());
+
+    // legacy token with 24 decimals, rounded down
+    call::set_minter_rate(&contract, &owner, bridge.id(), 1, 1, 24).await?;
+    let res = call::mint(&contract, &bridge, user.id(), 1_999_999.into()).await?;
+    assert_eq!(res.json::<U128>()?.0, 1);
+    assert!(call::mint(&contract, &bridge, user.id(), 999_999.into())
+        .await
+        .is_err());
+
+    call::set_minter_rate(&contract, &owner, bridge.id(), 3, 2, 18).await?;
+    let res = call::mint(&contract, &bridge, user.id(), 5.into()).await?;
+    assert_eq!(res.json::<U128>()?.0, 7);
+    let supply = 1_000_000_000_000 + 1 + 7;
+    assert_eq!(view::ft_total_supply(&contract).await?.0, supply);
+    assert_eq!(
+        view::minter(&contract, bridge.id())
+            .await?
+            .unwrap()
+            .minted
+            .0,
+        supply
+    );
+
+    // amounts at the u128 boundary
+    call::set_minter_rate(&contract, &owner, bridge.id(), 2, 1, 18).await?;
+    assert!(
+        call::mint(&contract, &bridge, user.id(), (u128::MAX / 2 + 1).into())
+            .await
+            .is_err()
+    );
+    call::set_minter_rate(&contract, &owner, bridge.id(), 3, 3, 18).await?;
+    let res = call::mint(&contract, &bridge, user.id(), (u128::MAX - supply).into()).await?;
+    assert_eq!(res.json::<U128>()?.0, u128::MAX - supply);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, u128::MAX);
+    assert!(call::mint(&contract, &bridge, user.id(), 3.into())
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_signed_claim() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+    let relayer = worker.dev_create_account().await?;
+
+    let holder = evm::EvmKey::new(1)?;
+    let stranger = evm::EvmKey::new(2)?;
+    let address = holder.address()?;
+    assert!(
+        call::set_claim_snapshot(&contract, &user, &[(&address, 1_000)])
+            .await
+            .is_err()
+    );
+    call::set_claim_snapshot(&contract, &owner, &[(&address, 1_000)]).await?;
+    let claim_account = view::claim_account(&contract, &address).await?.unwrap();
+    assert_eq!(claim_account.balance.0, 1_000);
+    assert_eq!(claim_account.nonce.0, 0);
+    assert!(view::claim_account(&contract, &stranger.address()?)
+        .await?
+        .is_none());
+
+    let now = worker.view_block().await?.timestamp();
+    let deadline = now + 3_600_000_000_000;
+    let message = view::claim_signed_message(&contract, user.id(), 400, 0, deadline).await?;
+    let signature = holder.personal_sign(&message)?;
+
+    // signatures only cover the signed arguments
+    assert!(
+        call::claim_signed(&contract, &relayer, user.id(), 500, 0, deadline, &signature)
+            .await
+            .is_err()
+    );
+    assert!(call::claim_signed(
+        &contract,
+        &relayer,
+        relayer.id(),
+        400,
+        0,
+        deadline,
+        &signature
+    )
+    .await
+    .is_err());
+    let stranger_signature = stranger.personal_sign(&message)?;
+    assert!(call::claim_signed(
+        &contract,
+        &relayer,
+        user.id(),
+        400,
+        0,
+        deadline,
+        &stranger_signature
+    )
+    .await
+    .is_err());
+
+    let res =
+        call::claim_signed(&contract, &relayer, user.id(), 400, 0, deadline, &signature).await?;
+    assert_eq!(res.json::<U128>()?.0, 400);
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 400);
+    let claim_account = view::claim_account(&contract, &address).await?.unwrap();
+    assert_eq!(claim_account.claimed.0, 400);
+    assert_eq!(claim_account.nonce.0, 1);
+    assert_eq!(
+        view::migration_stats(&contract).await?.total_migrated.0,
+        400
+    );
+
+    // signatures can not be replayed
+    assert!(
+        call::claim_signed(&contract, &relayer, user.id(), 400, 0, deadline, &signature)
+            .await
+            .is_err()
+    );
+
+    let message = view::claim_signed_message(&contract, user.id(), 700, 1, deadline).await?;
+    let signature = holder.personal_sign(&message)?;
+    assert!(
+        call::claim_signed(&contract, &relayer, user.id(), 700, 1, deadline, &signature)
+            .await
+            .is_err()
+    );
+
+    let message = view::claim_signed_message(&contract, user.id(), 600, 1, now).await?;
+    let signature = holder.personal_sign(&message)?;
+    assert!(
+        call::claim_signed(&contract, &relayer, user.id(), 600, 1, now, &signature)
+            .await
+            .is_err()
+    );
+
+    assert!(
+        call::set_claim_snapshot(&contract, &owner, &[(&address, 399)])
+            .await
+            .is_err()
+    );
+    call::set_claim_snapshot(&contract, &owner, &[(&address, 1_100)]).await?;
+    let message = view::claim_signed_message(&contract, user.id(), 700, 1, deadline).await?;
+    let signature = holder.personal_sign(&message)?;
+    call::claim_signed(&contract, &relayer, user.id(), 700, 1, deadline, &signature).await?;
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_100);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 1_100);
+
+    // the bridge is closed by the snapshot, as claimed tokens can still be moved on Aurora
+    for aurora_sender in [Some(address.as_str()), None] {
+        assert!(
+            call::mint_migration(&contract, &bridge, user.id(), 1.into(), None, aurora_sender)
+                .await
+                .is_err()
+        );
+    }
+
+    // claimed tokens can not be migrated by other minters again
+    let minter = worker.dev_create_account().await?;
+    call::add_minter(&contract, &owner, minter.id(), None, None).await?;
+    assert!(call::mint_migration(
+        &contract,
+        &minter,
+        user.id(),
+        1.into(),
+        None,
+        Some(address.as_str())
+    )
+    .await
+    .is_err());
+
+    // migrations of other minters reduce the claimable balance in SHITZUv1
+    let migrator = evm::EvmKey::new(3)?.address()?;
+    call::set_claim_snapshot(&contract, &owner, &[(&migrator, 1_000)]).await?;
+    call::set_minter_rate(&contract, &owner, minter.id(), 2, 1, 18).await?;
+    call::mint_migration(
+        &contract,
+        &minter,
+        user.id(),
+        300.into(),
+        None,
+        Some(migrator.as_str()),
+    )
+    .await?;
+    let claim_account = view::claim_account(&contract, &migrator).await?.unwrap();
+    assert_eq!(claim_account.claimed.0, 300);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 1_700);
+
+    // claims are converted with the exchange rate of the bridge
+    call::set_minter_rate(&contract, &owner, bridge.id(), 2, 1, 18).await?;
+    let holder = evm::EvmKey::new(4)?;
+    call::set_claim_snapshot(&contract, &owner, &[(&holder.address()?, 1_000)]).await?;
+    let message = view::claim_signed_message(&contract, user.id(), 100, 0, deadline).await?;
+    let signature = holder.personal_sign(&message)?;
+    let res =
+        call::claim_signed(&contract, &relayer, user.id(), 100, 0, deadline, &signature).await?;
+    assert_eq!(res.json::<U128>()?.0, 200);
+    let claim_account = view::claim_account(&contract, &holder.address()?)
+        .await?
+        .unwrap();
+    assert_eq!(claim_account.claimed.0, 100);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 1_900);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_airdrop_campaign() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let alice = worker.dev_create_account().await?;
+    let bob = worker.dev_create_account().await?;
+    let carol = worker.dev_create_account().await?;
+    let treasury = worker.dev_create_account().await?;
+    call::mint(&contract, &bridge, owner.id(), 10_000.into()).await?;
+
+    let tree = merkle::MerkleTree::new(&[(alice.id(), 100), (bob.id(), 200), (carol.id(), 300)])?;
+    let expires_at = worker.view_block().await?.timestamp() + 20_000_000_000;
+    assert!(call::create_airdrop_campaign(
+        &contract,
+        &alice,
+        tree.root(),
+        600,
+        expires_at,
+        treasury.id()
+    )
+    .await
+    .is_err());
+    let res = call::create_airdrop_campaign(
+        &contract,
+        &owner,
+        tree.root(),
+        600,
+        expires_at,
+        treasury.id(),
+    )
+    .await?;
+    let campaign_id = res.json::<U64>()?.0;
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 9_400);
+    assert_eq!(view::ft_balance_of(&contract, contract.id()).await?.0, 600);
+
+    // proofs only hold for the leaf of the predecessor
+    assert!(
+        call::claim(&contract, &alice, campaign_id, 150, tree.proof(0))
+            .await
+            .is_err()
+    );
+    assert!(
+        call::claim(&contract, &alice, campaign_id, 200, tree.proof(1))
+            .await
+            .is_err()
+    );
+    call::claim(&contract, &alice, campaign_id, 100, tree.proof(0)).await?;
+    assert_eq!(view::ft_balance_of(&contract, alice.id()).await?.0, 100);
+    assert!(view::is_airdrop_claimed(&contract, campaign_id, alice.id(), 100).await?);
+    assert!(
+        call::claim(&contract, &alice, campaign_id, 100, tree.proof(0))
+            .await
+            .is_err()
+    );
+    call::claim(&contract, &bob, campaign_id, 200, tree.proof(1)).await?;
+    assert_eq!(view::ft_balance_of(&contract, bob.id()).await?.0, 200);
+
+    // tokens in escrow can not be recovered
+    call::ft_transfer(&bob, contract.id(), contract.id(), 50).await?;
+    owner
+        .call(contract.id(), "recover")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 9_450);
+    assert_eq!(view::ft_balance_of(&contract, contract.id()).await?.0, 300);
+
+    assert!(call::close_airdrop_campaign(&contract, &alice, campaign_id)
+        .await
+        .is_err());
+    while worker.view_block().await?.timestamp() < expires_at {
+        worker.fast_forward(100).await?;
+    }
+    assert!(
+        call::claim(&contract, &carol, campaign_id, 300, tree.proof(2))
+            .await
+            .is_err()
+    );
+    let res = call::close_airdrop_campaign(&contract, &alice, campaign_id).await?;
+    assert_eq!(res.json::<U128>()?.0, 300);
+    assert_eq!(view::ft_balance_of(&contract, treasury.id()).await?.0, 300);
+    assert_eq!(view::ft_balance_of(&contract, contract.id()).await?.0, 0);
+    let campaign = view::airdrop_campaign(&contract, campaign_id)
+        .await?
+        .unwrap();
+    assert!(campaign.closed);
+    assert_eq!(campaign.claimed_amount.0, 300);
+    assert!(call::close_airdrop_campaign(&contract, &alice, campaign_id)
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migration_bonus() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+    call::mint(&contract, &bridge, owner.id(), 10_000.into()).await?;
+
+    let now = worker.view_block().await?.timestamp();
+    let first_tier = now + 20_000_000_000;
+    let second_tier = now + 3_600_000_000_000;
+    assert!(
+        call::set_bonus_schedule(&contract, &user, &[(first_tier, 1_000), (second_tier, 500)])
+            .await
+            .is_err()
+    );
+    assert!(call::set_bonus_schedule(
+        &contract,
+        &owner,
+        &[(first_tier, 500), (second_tier, 1_000)]
+    )
+    .await
+    .is_err());
+    assert!(
+        call::set_bonus_schedule(&contract, &owner, &[(first_tier, 10_001)])
+            .await
+            .is_err()
+    );
+    call::set_bonus_schedule(
+        &contract,
+        &owner,
+        &[(first_tier, 1_000), (second_tier, 500)],
+    )
+    .await?;
+    assert!(call::fund_bonus_pool(&contract, &user, 150).await.is_err());
+    let res = call::fund_bonus_pool(&contract, &owner, 150).await?;
+    let update = event::parse_events(res.logs())?
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::BonusPoolUpdate(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(update.balance, "150");
+    let bonus_pool = view::bonus_pool(&contract).await?;
+    assert_eq!(bonus_pool.balance.0, 150);
+    assert_eq!(bonus_pool.current_bonus_bps, 1_000);
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 9_850);
+
+    let res = call::mint(&contract, &bridge, user.id(), 1_000.into()).await?;
+    let events = event::parse_events(res.logs())?;
+    let migrate = events
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::ShitzuMigrate(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(migrate.amount, "1000");
+    assert_eq!(migrate.bonus.as_deref(), Some("100"));
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_100);
+    // the bonus is not minted
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 11_000);
+
+    while worker.view_block().await?.timestamp() < first_tier {
+        worker.fast_forward(100).await?;
+    }
+    assert_eq!(view::bonus_pool(&contract).await?.current_bonus_bps, 500);
+    call::mint(&contract, &bridge, user.id(), 600.into()).await?;
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_730);
+
+    // the bonus is limited by the pool and stops once it is empty
+    call::mint(&contract, &bridge, user.id(), 1_000.into()).await?;
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 2_750);
+    call::mint(&contract, &bridge, user.id(), 1_000.into()).await?;
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 3_750);
+    let bonus_pool = view::bonus_pool(&contract).await?;
+    assert_eq!(bonus_pool.balance.0, 0);
+    assert_eq!(bonus_pool.paid.0, 150);
+
+    // the bonus pool can not be recovered, only withdrawn by the owner
+    call::fund_bonus_pool(&contract, &owner, 50).await?;
+    assert!(owner
+        .call(contract.id(), "recover")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()
+        .is_err());
+    assert!(call::withdraw_bonus_pool(&contract, &owner, 51)
+        .await
+        .is_err());
+    let res = call::withdraw_bonus_pool(&contract, &owner, 50).await?;
+    let update = event::parse_events(res.logs())?
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::BonusPoolUpdate(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(update.balance, "0");
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 9_850);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_lock() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+    call::storage_deposit(&contract, &owner, None, None, None).await?;
+
+    let now = worker.view_block().await?.timestamp();
+    let unlocks_at = now + 20_000_000_000;
+    let cliff = serde_json::json!({
+        "share_bps": 5_000,
+        "schedule": { "type": "cliff", "unlocks_at": U64(unlocks_at) },
+    });
+    assert!(call::set_mint_lock(&contract, &user, Some(cliff.clone()))
+        .await
+        .is_err());
+    for invalid in [
+        serde_json::json!({
+            "share_bps": 0,
+            "schedule": { "type": "cliff", "unlocks_at": U64(unlocks_at) },
+        }),
+        serde_json::json!({
+            "share_bps": 10_001,
+            "schedule": { "type": "cliff", "unlocks_at": U64(unlocks_at) },
+        }),
+        serde_json::json!({
+            "share_bps": 5_000,
+            "schedule": { "type": "linear", "start": U64(unlocks_at), "end": U64(now) },
+        }),
+    ] {
+        assert!(call::set_mint_lock(&contract, &owner, Some(invalid))
+            .await
+            .is_err());
+    }
+    call::set_mint_lock(&contract, &owner, Some(cliff)).await?;
+
+    let res = call::mint(&contract, &bridge, user.id(), 1_001.into()).await?;
+    let events = event::parse_events(res.logs())?;
+    let migrate = events
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::ShitzuMigrate(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(migrate.locked.as_deref(), Some("500"));
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_001);
+    assert_eq!(
+        view::ft_locked_balance_of(&contract, user.id()).await?.0,
+        500
+    );
+    assert_eq!(
+        view::ft_unlocked_balance_of(&contract, user.id()).await?.0,
+        501
+    );
+
+    assert!(call::ft_transfer(&user, contract.id(), owner.id(), 502)
+        .await
+        .is_err());
+    call::ft_transfer(&user, contract.id(), owner.id(), 501).await?;
+    assert!(call::ft_transfer(&user, contract.id(), owner.id(), 1)
+        .await
+        .is_err());
+
+    while worker.view_block().await?.timestamp() < unlocks_at {
+        worker.fast_forward(100).await?;
+    }
+    assert_eq!(view::ft_locked_balance_of(&contract, user.id()).await?.0, 0);
+    call::ft_transfer(&user, contract.id(), owner.id(), 500).await?;
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 1_001);
+
+    let now = worker.view_block().await?.timestamp();
+    let linear = serde_json::json!({
+        "share_bps": 10_000,
+        "schedule": {
+            "type": "linear",
+            "start": U64(now),
+            "end": U64(now + 3_600_000_000_000),
+        },
+    });
+    call::set_mint_lock(&contract, &owner, Some(linear)).await?;
+    call::mint(&contract, &bridge, user.id(), 1_000_000.into()).await?;
+    worker.fast_forward(100).await?;
+    let locked = view::ft_locked_balance_of(&contract, user.id()).await?.0;
+    let unlocked = view::ft_unlocked_balance_of(&contract, user.id()).await?.0;
+    assert!(locked < 1_000_000);
+    assert!(unlocked > 0);
+    assert_eq!(locked + unlocked, 1_000_000);
+    assert!(
+        call::ft_transfer(&user, contract.id(), owner.id(), unlocked + 100_000)
+            .await
+            .is_err()
+    );
+    call::ft_transfer(&user, contract.id(), owner.id(), unlocked).await?;
+
+    // mint_call only forwards the unlocked share
+    let migrator = worker.dev_create_account().await?;
+    let receiver = worker
+        .dev_deploy(&fs::read("../../res/mock_receiver.wasm").await?)
+        .await?;
+    call::storage_deposit(&contract, &owner, Some(receiver.id()), Some(true), None).await?;
+    let now = worker.view_block().await?.timestamp();
+    let cliff = serde_json::json!({
+        "share_bps": 4_000,
+        "schedule": { "type": "cliff", "unlocks_at": U64(now + 3_600_000_000_000) },
+    });
+    call::set_mint_lock(&contract, &owner, Some(cliff)).await?;
+    call::mint_call(
+        &contract,
+        &bridge,
+        migrator.id(),
+        receiver.id(),
+        1_000.into(),
+        "100",
+    )
+    .await?;
+    assert_eq!(view::ft_balance_of(&contract, receiver.id()).await?.0, 500);
+    assert_eq!(view::ft_balance_of(&contract, migrator.id()).await?.0, 500);
+    assert_eq!(
+        view::ft_locked_balance_of(&contract, migrator.id())
+            .await?
+            .0,
+        400
+    );
+
+    call::set_mint_lock(&contract, &owner, None).await?;
+    call::mint(&contract, &bridge, owner.id(), 1_000.into()).await?;
+    assert_eq!(
+        view::ft_locked_balance_of(&contract, owner.id()).await?.0,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mint_eth_implicit() -> anyhow::Result<()> {
+    let (_, owner, contract, bridge) = aurora::initialize_token().await?;
+
+    let eth_implicit_id: near_workspaces::AccountId =
+        "0x1f9840a85d5af5bf1d1762f925bdaddc4201f984".parse()?;
+    call::mint(&contract, &bridge, &eth_implicit_id, 1_000.into()).await?;
+    assert_eq!(
+        view::ft_balance_of(&contract, &eth_implicit_id).await?.0,
+        1_000
+    );
+    call::mint(&contract, &bridge, owner.id(), 2_000.into()).await?;
+    assert_eq!(view::ft_balance_of(&contract, owner.id()).await?.0, 2_000);
+
+    // named sub-accounts starting with 0x are not ETH-implicit accounts
+    let named_id: near_workspaces::AccountId = format!("0xabc.{}", contract.id()).parse()?;
+    call::mint(&contract, &bridge, &named_id, 3_000.into()).await?;
+    assert_eq!(view::ft_balance_of(&contract, &named_id).await?.0, 3_000);
+
+    for account_id in [
+        "0x1f9840a85d5af5bf1d1762f925bdaddc4201f9",
+        "0x1f9840a85d5af5bf1d1762f925bdaddc4201f98400",
+        "0x1f9840a85d5af5bf1d1762f925bdaddc4201fxyz",
+    ] {
+        let account_id: near_workspaces::AccountId = account_id.parse()?;
+        assert!(call::mint(&contract, &bridge, &account_id, 1_000.into())
+            .await
+            .is_err());
+    }
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 6_000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_recovery() -> anyhow::Result<()> {
+    let (worker, owner, contract, bridge) = aurora::initialize_token().await?;
+    let user = worker.dev_create_account().await?;
+
+    let source_id: near_workspaces::AccountId = "114155".parse()?;
+    let long_id: near_workspaces::AccountId = "1".repeat(40).parse()?;
+    let named_id: near_workspaces::AccountId = "aurora".parse()?;
+    for account_id in [&source_id, &long_id, &named_id, user.id()] {
+        call::mint(&contract, &bridge, account_id, 1_000.into()).await?;
+    }
+
+    // accounts that may hold keys are not eligible
+    for account_id in [user.id(), contract.id(), &long_id, &named_id] {
+        assert!(
+            call::file_recovery(&contract, &owner, account_id, 100, owner.id())
+                .await
+                .is_err()
+        );
+    }
+    assert!(
+        call::file_recovery(&contract, &user, &source_id, 100, user.id())
+            .await
+            .is_err()
+    );
+    assert!(
+        call::file_recovery(&contract, &owner, &source_id, 1_001, user.id())
+            .await
+            .is_err()
+    );
+    assert!(call::set_recovery_timelock(&contract, &owner, 1_000)
+        .await
+        .is_err());
+    call::set_recovery_timelock(&contract, &owner, 86_400_000_000_000).await?;
+
+    let res = call::file_recovery(&contract, &owner, &source_id, 400, user.id()).await?;
+    let id = res.json::<U64>()?.0;
+    let events = event::parse_events(res.logs())?;
+    assert!(events.iter().any(|event| matches!(
+        event,
+        event::ContractEvent::Shitzu(event::ShitzuEvent {
+            event_kind: event::ShitzuEventKind::RecoveryFiled(_),
+            ..
+        })
+    )));
+    let res = call::file_recovery(&contract, &owner, &source_id, 600, owner.id()).await?;
+    let cancelled_id = res.json::<U64>()?.0;
+    let requests = view::recovery_requests(&contract).await?;
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].id.0, id);
+    assert_eq!(requests[0].source_id, source_id);
+    assert_eq!(requests[0].amount.0, 400);
+    assert_eq!(&requests[0].destination_id, user.id());
+    let executable_at = requests[0].executable_at.0;
+
+    assert!(call::execute_recovery(&contract, &owner, id).await.is_err());
+    assert!(call::cancel_recovery(&contract, &user, cancelled_id)
+        .await
+        .is_err());
+    call::cancel_recovery(&contract, &owner, cancelled_id).await?;
+    assert_eq!(view::recovery_requests(&contract).await?.len(), 1);
+
+    while worker.view_block().await?.timestamp() < executable_at {
+        worker.fast_forward(10_000).await?;
+    }
+    assert!(call::execute_recovery(&contract, &user, id).await.is_err());
+    let res = call::execute_recovery(&contract, &owner, id).await?;
+    let events = event::parse_events(res.logs())?;
+    assert!(events.iter().any(|event| matches!(
+        event,
+        event::ContractEvent::Shitzu(event::ShitzuEvent {
+            event_kind: event::ShitzuEventKind::RecoveryExecuted(_),
+            ..
+        })
+    )));
+    assert_eq!(view::ft_balance_of(&contract, &source_id).await?.0, 600);
+    assert_eq!(view::ft_balance_of(&contract, user.id()).await?.0, 1_400);
+    assert!(view::recovery_requests(&contract).await?.is_empty());
+    assert!(call::execute_recovery(&contract, &owner, id).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_foreign_token_recovery() -> anyhow::Result<()> {
+    let (worker, owner, contract, _) = aurora::initialize_token().await?;
+    let alice = worker.dev_create_account().await?;
+    let bob = worker.dev_create_account().await?;
+
+    let foreign = worker
+        .dev_deploy(&fs::read("../../res/test_token.wasm").await?)
+        .await?;
+    foreign
+        .call("new")
+        .args_json(("USD Coin", "USDC", None::<String>, 6))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    for account_id in [alice.id(), bob.id(), contract.id(), owner.id()] {
+        call::storage_deposit(&foreign, &owner, Some(account_id), Some(true), None).await?;
+    }
+    for account_id in [alice.id(), bob.id()] {
+        foreign
+            .call("mint")
+            .args_json((account_id, "1000"))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    // tokens are refunded unless the sender has registered the token
+    call::ft_transfer_call(&alice, foreign.id(), contract.id(), 300, "").await?;
+    assert_eq!(view::ft_balance_of(&foreign, alice.id()).await?.0, 1_000);
+    assert!(view::foreign_balances(&contract).await?.is_empty());
+
+    let cost = view::foreign_token_storage_cost(&contract).await?;
+    assert!(call::register_foreign_token(
+        &contract,
+        &alice,
+        foreign.id(),
+        cost.saturating_sub(NearToken::from_yoctonear(1))
+    )
+    .await
+    .is_err());
+    for account in [&alice, &bob] {
+        call::register_foreign_token(&contract, account, foreign.id(), cost).await?;
+    }
+    assert!(
+        call::register_foreign_token(&contract, &alice, foreign.id(), cost)
+            .await
+            .is_err()
+    );
+
+    // ft_on_transfer can not be called directly, not even on behalf of a registered sender
+    let user = worker.dev_create_account().await?;
+    for sender_id in [user.id(), alice.id()] {
+        assert!(user
+            .call(contract.id(), "ft_on_transfer")
+            .args_json((sender_id, "1000", ""))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()
+            .is_err());
+    }
+    let balances = view::foreign_balances(&contract).await?;
+    assert_eq!(balances.len(), 2);
+    assert!(balances.iter().all(|balance| balance.amount.0 == 0));
+
+    let res = call::ft_transfer_call(&alice, foreign.id(), contract.id(), 300, "").await?;
+    let events = event::parse_events(res.logs())?;
+    let received = events
+        .into_iter()
+        .find_map(|event| match event {
+            event::ContractEvent::Shitzu(event::ShitzuEvent {
+                event_kind: event::ShitzuEventKind::ForeignTokenReceived(mut data),
+                ..
+            }) => data.pop(),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(received.sender_id, alice.id().as_str());
+    assert_eq!(received.amount, "300");
+    call::ft_transfer_call(&alice, foreign.id(), contract.id(), 200, "").await?;
+    call::ft_transfer_call(&bob, foreign.id(), contract.id(), 1_000, "").await?;
+    assert_eq!(view::ft_balance_of(&foreign, contract.id()).await?.0, 1_500);
+    assert_eq!(view::ft_total_supply(&contract).await?.0, 0);
+
+    let balances = view::foreign_balances(&contract).await?;
+    assert_eq!(balances.len(), 2);
+    assert_eq!(&balances[0].token_id, foreign.id());
+    assert_eq!(&balances[0].sender_id, alice.id());
+    assert_eq!(balances[0].amount.0, 500);
+    assert_eq!(&balances[1].sender_id, bob.id());
+    assert_eq!(balances[1].amount.0, 1_000);
+
+    // only the owner or the sender can withdraw, which refunds the registration deposit
+    assert!(
+        call::withdraw_foreign_token(&contract, &bob, foreign.id(), alice.id())
+            .await
+            .is_err()
+    );
+    let balance = alice.view_account().await?.balance;
+    call::withdraw_foreign_token(&contract, &alice, foreign.id(), alice.id()).await?;
+    assert_eq!(view::ft_balance_of(&foreign, alice.id()).await?.0, 1_000);
+    assert!(alice.view_account().await?.balance > balance);
+    assert!(
+        call::withdraw_foreign_token(&contract, &alice, foreign.id(), alice.id())
+            .await
+            .is_err()
+    );
+
+    // tokens are credited back if the transfer fails
+    call::storage_unregister(&foreign, &bob, None).await?;
+    let res = call::withdraw_foreign_token(&contract, &owner, foreign.id(), bob.id()).await?;
+    assert_eq!(res.json::<U128>()?.0, 0);
+    let balances = view::foreign_balances(&contract).await?;
+    assert_eq!(balances.len(), 1);
+    assert_eq!(&balances[0].sender_id, bob.id());
+    assert_eq!(balances[0].amount.0, 1_000);
+
+    call::storage_deposit(&foreign, &owner, Some(bob.id()), Some(true), None).await?;
+    let res = call::withdraw_foreign_token(&contract, &owner, foreign.id(), bob.id()).await?;
+    assert_eq!(res.json::<U128>()?.0, 1_000);
+    assert_eq!(view::ft_balance_of(&foreign, bob.id()).await?.0, 1_000);
+    assert!(view::foreign_balances(&contract).await?.is_empty());
+
+    // the number of registered tokens per sender is limited
+    for index in 0..10 {
+        let token_id: near_workspaces::AccountId = format!("token-{index}.test.near").parse()?;
+        call::register_foreign_token(&contract, &user, &token_id, cost).await?;
+    }
+    assert!(
+        call::register_foreign_token(&contract, &user, foreign.id(), cost)
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
 use super::{log_tx_result, Action, DaoConfig, DaoPolicy, ProposalInput};
 use near_sdk::{
-    json_types::{Base58CryptoHash, U128},
+    borsh,
+    json_types::{Base58CryptoHash, Base64VecU8, U128, U64},
     NearToken,
 };
 use near_workspaces::{
     result::{ExecutionResult, Value},
     Account, AccountId, Contract,
 };
+use serde_json::json;
+use token::MintArgs;
 
 pub async fn storage_deposit(
     contract: &Contract,
@@ -27,6 +30,336 @@ pub async fn storage_deposit(
     )
 }
 
+pub async fn storage_unregister(
+    contract: &Contract,
+    sender: &Account,
+    force: Option<bool>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("storage_unregister"),
+        sender
+            .call(contract.id(), "storage_unregister")
+            .args_json((force,))
+            .deposit(NearToken::from_yoctonear(1))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_legacy_token(
+    contract: &Contract,
+    sender: &Account,
+    token_id: &AccountId,
+    enabled: bool,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_legacy_token"),
+        sender
+            .call(contract.id(), "set_legacy_token")
+            .args_json((token_id, enabled))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_claim_snapshot(
+    contract: &Contract,
+    sender: &Account,
+    entries: &[(&str, u128)],
+) -> anyhow::Result<ExecutionResult<Value>> {
+    let entries: Vec<_> = entries
+        .iter()
+        .map(|(address, balance)| json!({ "address": address, "balance": U128(*balance) }))
+        .collect();
+    log_tx_result(
+        Some("set_claim_snapshot"),
+        sender
+            .call(contract.id(), "set_claim_snapshot")
+            .args_json((entries,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn claim_signed(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    amount: u128,
+    nonce: u64,
+    deadline: u64,
+    signature: &str,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("claim_signed"),
+        sender
+            .call(contract.id(), "claim_signed")
+            .args_json((
+                account_id,
+                U128(amount),
+                U64(nonce),
+                U64(deadline),
+                signature,
+            ))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn create_airdrop_campaign(
+    contract: &Contract,
+    sender: &Account,
+    merkle_root: Base58CryptoHash,
+    total_amount: u128,
+    expires_at: u64,
+    treasury_id: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("create_airdrop_campaign"),
+        sender
+            .call(contract.id(), "create_airdrop_campaign")
+            .args_json((
+                merkle_root,
+                U128(total_amount),
+                U64(expires_at),
+                treasury_id,
+            ))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn claim(
+    contract: &Contract,
+    sender: &Account,
+    campaign_id: u64,
+    amount: u128,
+    proof: Vec<Base58CryptoHash>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("claim"),
+        sender
+            .call(contract.id(), "claim")
+            .args_json((U64(campaign_id), U128(amount), proof))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn close_airdrop_campaign(
+    contract: &Contract,
+    sender: &Account,
+    campaign_id: u64,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("close_airdrop_campaign"),
+        sender
+            .call(contract.id(), "close_airdrop_campaign")
+            .args_json((U64(campaign_id),))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_bonus_schedule(
+    contract: &Contract,
+    sender: &Account,
+    schedule: &[(u64, u16)],
+) -> anyhow::Result<ExecutionResult<Value>> {
+    let schedule: Vec<_> = schedule
+        .iter()
+        .map(|(until, bonus_bps)| json!({ "until": U64(*until), "bonus_bps": bonus_bps }))
+        .collect();
+    log_tx_result(
+        Some("set_bonus_schedule"),
+        sender
+            .call(contract.id(), "set_bonus_schedule")
+            .args_json((schedule,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn fund_bonus_pool(
+    contract: &Contract,
+    sender: &Account,
+    amount: u128,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("fund_bonus_pool"),
+        sender
+            .call(contract.id(), "fund_bonus_pool")
+            .args_json((U128(amount),))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn withdraw_bonus_pool(
+    contract: &Contract,
+    sender: &Account,
+    amount: u128,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("withdraw_bonus_pool"),
+        sender
+            .call(contract.id(), "withdraw_bonus_pool")
+            .args_json((U128(amount),))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_mint_lock(
+    contract: &Contract,
+    sender: &Account,
+    mint_lock: Option<serde_json::Value>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_mint_lock"),
+        sender
+            .call(contract.id(), "set_mint_lock")
+            .args_json((mint_lock,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn file_recovery(
+    contract: &Contract,
+    sender: &Account,
+    source_id: &AccountId,
+    amount: u128,
+    destination_id: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("file_recovery"),
+        sender
+            .call(contract.id(), "file_recovery")
+            .args_json((
+                source_id,
+                U128(amount),
+                destination_id,
+                "Sent to the wrong account",
+                "2zmB5uumyaUf4hzCeDyaqH81Fpk9b2iRoAZq2Na3bP3C",
+            ))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn execute_recovery(
+    contract: &Contract,
+    sender: &Account,
+    id: u64,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("execute_recovery"),
+        sender
+            .call(contract.id(), "execute_recovery")
+            .args_json((U64(id),))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn cancel_recovery(
+    contract: &Contract,
+    sender: &Account,
+    id: u64,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("cancel_recovery"),
+        sender
+            .call(contract.id(), "cancel_recovery")
+            .args_json((U64(id),))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_recovery_timelock(
+    contract: &Contract,
+    sender: &Account,
+    timelock: u64,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_recovery_timelock"),
+        sender
+            .call(contract.id(), "set_recovery_timelock")
+            .args_json((U64(timelock),))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn register_foreign_token(
+    contract: &Contract,
+    sender: &Account,
+    token_id: &AccountId,
+    deposit: NearToken,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("register_foreign_token"),
+        sender
+            .call(contract.id(), "register_foreign_token")
+            .args_json((token_id,))
+            .deposit(deposit)
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn withdraw_foreign_token(
+    contract: &Contract,
+    sender: &Account,
+    token_id: &AccountId,
+    sender_id: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("withdraw_foreign_token"),
+        sender
+            .call(contract.id(), "withdraw_foreign_token")
+            .args_json((token_id, sender_id))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_storage_sponsorship_budget(
+    contract: &Contract,
+    sender: &Account,
+    max_count: Option<U64>,
+    max_amount: Option<NearToken>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_storage_sponsorship_budget"),
+        sender
+            .call(contract.id(), "set_storage_sponsorship_budget")
+            .args_json((max_count, max_amount))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
 pub async fn mint(
     contract: &Contract,
     sender: &Account,
@@ -37,7 +370,438 @@ pub async fn mint(
         Some("mint"),
         sender
             .call(contract.id(), "mint")
-            .args_json((account_id, amount))
+            .args_json(json!({
+                "account_id": account_id,
+                "amount": amount,
+            }))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn mint_migration(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    amount: U128,
+    migration_id: Option<U64>,
+    aurora_sender: Option<&str>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("mint"),
+        sender
+            .call(contract.id(), "mint")
+            .args_json(json!({
+                "account_id": account_id,
+                "amount": amount,
+                "migration_id": migration_id,
+                "aurora_sender": aurora_sender,
+            }))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn mint_call(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    receiver_id: &AccountId,
+    amount: U128,
+    msg: &str,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("mint_call"),
+        sender
+            .call(contract.id(), "mint_call")
+            .args_json(json!({
+                "account_id": account_id,
+                "receiver_id": receiver_id,
+                "amount": amount,
+                "msg": msg,
+            }))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn mint_borsh(
+    contract: &Contract,
+    sender: &Account,
+    args: &MintArgs,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("mint_borsh"),
+        sender
+            .call(contract.id(), "mint_borsh")
+            .args(borsh::to_vec(args)?)
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_ft_metadata(
+    contract: &Contract,
+    sender: &Account,
+    icon: Option<String>,
+    reference: Option<String>,
+    reference_hash: Option<Base64VecU8>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_ft_metadata"),
+        sender
+            .call(contract.id(), "set_ft_metadata")
+            .args_json((icon, reference, reference_hash))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn grant_role(
+    contract: &Contract,
+    sender: &Account,
+    role: &str,
+    account_id: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("grant_role"),
+        sender
+            .call(contract.id(), "grant_role")
+            .args_json((role, account_id))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn revoke_role(
+    contract: &Contract,
+    sender: &Account,
+    role: &str,
+    account_id: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("revoke_role"),
+        sender
+            .call(contract.id(), "revoke_role")
+            .args_json((role, account_id))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn renounce_role(
+    contract: &Contract,
+    sender: &Account,
+    role: &str,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("renounce_role"),
+        sender
+            .call(contract.id(), "renounce_role")
+            .args_json((role,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn propose_owner(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    expires_at: Option<U64>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("propose_owner"),
+        sender
+            .call(contract.id(), "propose_owner")
+            .args_json((account_id, expires_at))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn accept_ownership(
+    contract: &Contract,
+    sender: &Account,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("accept_ownership"),
+        sender
+            .call(contract.id(), "accept_ownership")
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn cancel_ownership_transfer(
+    contract: &Contract,
+    sender: &Account,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("cancel_ownership_transfer"),
+        sender
+            .call(contract.id(), "cancel_ownership_transfer")
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn pause(
+    contract: &Contract,
+    sender: &Account,
+    categories: &[&str],
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("pause"),
+        sender
+            .call(contract.id(), "pause")
+            .args_json((categories,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn unpause(
+    contract: &Contract,
+    sender: &Account,
+    categories: &[&str],
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("unpause"),
+        sender
+            .call(contract.id(), "unpause")
+            .args_json((categories,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_migration_window(
+    contract: &Contract,
+    sender: &Account,
+    opens_at: U64,
+    closes_at: Option<U64>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_migration_window"),
+        sender
+            .call(contract.id(), "set_migration_window")
+            .args_json((opens_at, closes_at))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn reopen_migration(
+    contract: &Contract,
+    sender: &Account,
+    closes_at: Option<U64>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("reopen_migration"),
+        sender
+            .call(contract.id(), "reopen_migration")
+            .args_json((closes_at,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn finalize_migration(
+    contract: &Contract,
+    sender: &Account,
+    treasury_id: Option<&AccountId>,
+    amount: Option<U128>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("finalize_migration"),
+        sender
+            .call(contract.id(), "finalize_migration")
+            .args_json((treasury_id, amount))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_max_migratable_supply(
+    contract: &Contract,
+    sender: &Account,
+    max_migratable_supply: Option<U128>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_max_migratable_supply"),
+        sender
+            .call(contract.id(), "set_max_migratable_supply")
+            .args_json((max_migratable_supply,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_migrate_address(
+    contract: &Contract,
+    sender: &Account,
+    migrate_address: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_migrate_address"),
+        sender
+            .call(contract.id(), "set_migrate_address")
+            .args_json((migrate_address,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_migrate_evm_address(
+    contract: &Contract,
+    sender: &Account,
+    evm_address: &str,
+    engine_id: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_migrate_evm_address"),
+        sender
+            .call(contract.id(), "set_migrate_evm_address")
+            .args_json((evm_address, engine_id))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn add_minter(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    quota: Option<U128>,
+    expires_at: Option<U64>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("add_minter"),
+        sender
+            .call(contract.id(), "add_minter")
+            .args_json(json!({
+                "account_id": account_id,
+                "quota": quota,
+                "expires_at": expires_at,
+            }))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn update_minter(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    quota: Option<U128>,
+    expires_at: Option<U64>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("update_minter"),
+        sender
+            .call(contract.id(), "update_minter")
+            .args_json(json!({
+                "account_id": account_id,
+                "quota": quota,
+                "expires_at": expires_at,
+            }))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_minter_rate(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    numerator: u128,
+    denominator: u128,
+    decimals: u8,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_minter_rate"),
+        sender
+            .call(contract.id(), "set_minter_rate")
+            .args_json(json!({
+                "account_id": account_id,
+                "rate": {
+                    "numerator": U128(numerator),
+                    "denominator": U128(denominator),
+                    "decimals": decimals,
+                },
+            }))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_minter_enabled(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+    enabled: bool,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_minter_enabled"),
+        sender
+            .call(contract.id(), "set_minter_enabled")
+            .args_json((account_id, enabled))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn remove_minter(
+    contract: &Contract,
+    sender: &Account,
+    account_id: &AccountId,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("remove_minter"),
+        sender
+            .call(contract.id(), "remove_minter")
+            .args_json((account_id,))
+            .max_gas()
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn set_mint_rate_limit(
+    contract: &Contract,
+    sender: &Account,
+    window: U64,
+    limit: Option<U128>,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("set_mint_rate_limit"),
+        sender
+            .call(contract.id(), "set_mint_rate_limit")
+            .args_json((window, limit))
             .max_gas()
             .transact()
             .await?,
@@ -56,7 +820,26 @@ pub async fn ft_transfer(
             .call(token_id, "ft_transfer")
             .args_json((receiver_id, U128(amount), Option::<String>::None))
             .max_gas()
-            .deposit(NearToken::from_yoctonear(10))
+            .deposit(NearToken::from_yoctonear(1))
+            .transact()
+            .await?,
+    )
+}
+
+pub async fn ft_transfer_call(
+    sender: &Account,
+    token_id: &AccountId,
+    receiver_id: &AccountId,
+    amount: u128,
+    msg: &str,
+) -> anyhow::Result<ExecutionResult<Value>> {
+    log_tx_result(
+        Some("ft_transfer_call"),
+        sender
+            .call(token_id, "ft_transfer_call")
+            .args_json((receiver_id, U128(amount), Option::<String>::None, msg))
+            .max_gas()
+            .deposit(NearToken::from_yoctonear(1))
             .transact()
             .await?,
     )
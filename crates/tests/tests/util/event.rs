@@ -2,11 +2,22 @@ use owo_colors::OwoColorize;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
+/// Parses all events that have been logged in `logs`.
+pub fn parse_events<'a>(
+    logs: impl IntoIterator<Item = &'a str>,
+) -> anyhow::Result<Vec<ContractEvent>> {
+    logs.into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .map(|event| Ok(serde_json::from_str(event)?))
+        .collect()
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 #[serde(tag = "standard")]
 #[serde(rename_all = "kebab-case")]
 pub enum ContractEvent {
     Nep141(Nep141Event),
+    Shitzu(ShitzuEvent),
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -22,6 +33,7 @@ pub struct Nep141Event {
 pub enum Nep141EventKind {
     FtTransfer(Vec<FtTransfer>),
     FtMint(Vec<FtMint>),
+    FtBurn(Vec<FtBurn>),
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -39,10 +51,257 @@ pub struct FtMint {
     pub memo: Option<String>,
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct FtBurn {
+    pub owner_id: String,
+    pub amount: String,
+    pub memo: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ShitzuEvent {
+    pub version: String,
+    #[serde(flatten)]
+    pub event_kind: ShitzuEventKind,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+pub enum ShitzuEventKind {
+    FtMetadataUpdate(Vec<FtMetadataUpdate>),
+    RoleGranted(Vec<RoleChange>),
+    RoleRevoked(Vec<RoleChange>),
+    OwnershipTransferProposed(Vec<OwnershipTransferProposal>),
+    OwnershipTransferred(Vec<OwnershipTransferred>),
+    OwnershipTransferCancelled(Vec<OwnershipTransferProposal>),
+    Paused(Vec<PauseChange>),
+    Unpaused(Vec<PauseChange>),
+    MigrationWindowUpdate(Vec<MigrationWindow>),
+    MigrationReopened(Vec<MigrationWindow>),
+    MigrationFinalized(Vec<MigrationFinalized>),
+    MaxMigratableSupplyUpdate(Vec<MaxMigratableSupplyUpdate>),
+    ShitzuMigrate(Vec<ShitzuMigrate>),
+    MigrateAddressUpdate(Vec<MigrateAddressUpdate>),
+    MinterUpdate(Vec<MinterUpdate>),
+    MintRateLimitUpdate(Vec<MintRateLimitUpdate>),
+    MintRateLimitExceeded(Vec<MintRateLimitExceeded>),
+    StorageSponsorshipBudgetUpdate(Vec<StorageSponsorshipBudgetUpdate>),
+    LegacyTokenUpdate(Vec<LegacyTokenUpdate>),
+    ClaimSnapshotUpdate(Vec<ClaimSnapshotUpdate>),
+    AirdropCampaignCreated(Vec<AirdropCampaignCreated>),
+    AirdropClaimed(Vec<AirdropClaimed>),
+    AirdropCampaignClosed(Vec<AirdropCampaignClosed>),
+    BonusScheduleUpdate(Vec<BonusScheduleUpdate>),
+    BonusPoolUpdate(Vec<BonusPoolUpdate>),
+    MintLockUpdate(Vec<MintLockUpdate>),
+    RecoveryFiled(Vec<RecoveryFiled>),
+    RecoveryExecuted(Vec<RecoveryExecuted>),
+    RecoveryCancelled(Vec<RecoveryCancelled>),
+    RecoveryTimelockUpdate(Vec<RecoveryTimelockUpdate>),
+    ForeignTokenReceived(Vec<ForeignTokenTransfer>),
+    ForeignTokenReturned(Vec<ForeignTokenTransfer>),
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct FtMetadataUpdate {
+    pub reference: Option<String>,
+    pub reference_hash: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RoleChange {
+    pub role: String,
+    pub account_id: String,
+    pub by: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct OwnershipTransferProposal {
+    pub owner_id: String,
+    pub pending_owner_id: String,
+    pub expires_at: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct OwnershipTransferred {
+    pub old_owner_id: String,
+    pub new_owner_id: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PauseChange {
+    pub categories: Vec<String>,
+    pub by: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MigrationWindow {
+    pub opens_at: String,
+    pub closes_at: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MaxMigratableSupplyUpdate {
+    pub max_migratable_supply: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ShitzuMigrate {
+    pub account_id: String,
+    pub amount: String,
+    pub aurora_sender: Option<String>,
+    pub migration_id: Option<String>,
+    pub bonus: Option<String>,
+    pub locked: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MigrateAddressUpdate {
+    pub old_migrate_address: String,
+    pub new_migrate_address: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MigrationFinalized {
+    pub treasury_id: Option<String>,
+    pub amount: Option<String>,
+    pub by: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MinterUpdate {
+    pub account_id: String,
+    pub quota: Option<String>,
+    pub expires_at: Option<String>,
+    pub enabled: bool,
+    pub rate: ExchangeRate,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ExchangeRate {
+    pub numerator: String,
+    pub denominator: String,
+    pub decimals: u8,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MintRateLimitUpdate {
+    pub window: String,
+    pub limit: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct StorageSponsorshipBudgetUpdate {
+    pub max_count: Option<String>,
+    pub max_amount: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct LegacyTokenUpdate {
+    pub token_id: String,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ClaimSnapshotUpdate {
+    pub address: String,
+    pub balance: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AirdropCampaignCreated {
+    pub campaign_id: String,
+    pub merkle_root: String,
+    pub total_amount: String,
+    pub expires_at: String,
+    pub treasury_id: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AirdropClaimed {
+    pub campaign_id: String,
+    pub account_id: String,
+    pub amount: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct AirdropCampaignClosed {
+    pub campaign_id: String,
+    pub treasury_id: String,
+    pub amount: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct BonusScheduleUpdate {
+    pub schedule: Vec<BonusTier>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct BonusTier {
+    pub until: String,
+    pub bonus_bps: u16,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct BonusPoolUpdate {
+    pub balance: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MintLockUpdate {
+    pub mint_lock: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RecoveryFiled {
+    pub id: String,
+    pub source_id: String,
+    pub amount: String,
+    pub destination_id: String,
+    pub reason: String,
+    pub tx_reference: String,
+    pub executable_at: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RecoveryExecuted {
+    pub id: String,
+    pub source_id: String,
+    pub amount: String,
+    pub destination_id: String,
+    pub by: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RecoveryCancelled {
+    pub id: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RecoveryTimelockUpdate {
+    pub timelock: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct ForeignTokenTransfer {
+    pub token_id: String,
+    pub sender_id: String,
+    pub amount: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct MintRateLimitExceeded {
+    pub amount: String,
+    pub window_usage: String,
+    pub limit: String,
+}
+
 impl Display for ContractEvent {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             ContractEvent::Nep141(event) => formatter.write_fmt(format_args!("{}", event)),
+            ContractEvent::Shitzu(event) => formatter.write_fmt(format_args!("{}", event)),
         }
     }
 }
@@ -56,6 +315,9 @@ impl Display for Nep141Event {
             Nep141EventKind::FtMint(_) => {
                 formatter.write_fmt(format_args!("{}: ft_mint", "event".bright_cyan()))?;
             }
+            Nep141EventKind::FtBurn(_) => {
+                formatter.write_fmt(format_args!("{}: ft_burn", "event".bright_cyan()))?;
+            }
         }
         formatter.write_fmt(format_args!("\n{}: nep141", "standard".bright_cyan(),))?;
         formatter.write_fmt(format_args!(
@@ -74,6 +336,36 @@ impl Display for Nep141Event {
                     formatter.write_fmt(format_args!("\n{}: {}", "data".bright_cyan(), data))?;
                 }
             }
+            Nep141EventKind::FtBurn(datas) => {
+                for data in datas {
+                    formatter.write_fmt(format_args!("\n{}: {}", "data".bright_cyan(), data))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for ShitzuEvent {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        let event = serde_json::to_value(&self.event_kind).map_err(|_| fmt::Error)?;
+        formatter.write_fmt(format_args!(
+            "{}: {}",
+            "event".bright_cyan(),
+            event["event"].as_str().unwrap_or_default()
+        ))?;
+        formatter.write_fmt(format_args!("\n{}: shitzu", "standard".bright_cyan(),))?;
+        formatter.write_fmt(format_args!(
+            "\n{}: {}",
+            "version".bright_cyan(),
+            self.version
+        ))?;
+        for data in event["data"].as_array().into_iter().flatten() {
+            formatter.write_fmt(format_args!(
+                "\n{}: {}",
+                "data".bright_cyan(),
+                data.bright_blue()
+            ))?;
         }
         Ok(())
     }
@@ -120,3 +412,23 @@ impl Display for FtMint {
         Ok(())
     }
 }
+
+impl Display for FtBurn {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(memo) = &self.memo {
+            formatter.write_fmt(format_args!(
+                "{} ({}) --> {}",
+                self.owner_id.bright_blue(),
+                memo,
+                self.amount.bright_blue(),
+            ))?;
+        } else {
+            formatter.write_fmt(format_args!(
+                "{} --> {}",
+                self.owner_id.bright_blue(),
+                self.amount.bright_blue(),
+            ))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use near_sdk::env;
+use secp256k1::{key, Message, Secp256k1};
+
+/// Secp256k1 key of an EVM account, as used by Aurora wallets.
+pub struct EvmKey {
+    secp: Secp256k1,
+    secret_key: key::SecretKey,
+}
+
+impl EvmKey {
+    pub fn new(seed: u8) -> anyhow::Result<Self> {
+        let secp = Secp256k1::new();
+        let secret_key = key::SecretKey::from_slice(&secp, &[seed; 32])?;
+        Ok(Self { secp, secret_key })
+    }
+
+    /// Returns the 0x-prefixed lowercase hex encoded address.
+    pub fn address(&self) -> anyhow::Result<String> {
+        let public_key = key::PublicKey::from_secret_key(&self.secp, &self.secret_key)?;
+        let public_key = public_key.serialize_vec(&self.secp, false);
+        Ok(encode_hex(&env::keccak256(&public_key[1..])[12..]))
+    }
+
+    /// Signs `message` like `personal_sign` does and returns the 0x-prefixed
+    /// hex encoded `r || s || v` signature.
+    pub fn personal_sign(&self, message: &str) -> anyhow::Result<String> {
+        let hash = env::keccak256(
+            format!("\x19Ethereum Signed Message:\n{}{}", message.len(), message).as_bytes(),
+        );
+        let signature = self
+            .secp
+            .sign_recoverable(&Message::from_slice(&hash)?, &self.secret_key)?;
+        let (recovery_id, signature) = signature.serialize_compact(&self.secp);
+        let mut res = signature.to_vec();
+        res.push(27 + recovery_id.to_i32() as u8);
+        Ok(encode_hex(&res))
+    }
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("0x{}", hex)
+}
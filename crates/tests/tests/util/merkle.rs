@@ -0,0 +1,61 @@
+use near_sdk::{borsh, env, json_types::Base58CryptoHash, CryptoHash};
+use near_workspaces::AccountId;
+
+/// Merkle tree of airdrop leaves, hashed the same way as the token contract does.
+pub struct MerkleTree {
+    levels: Vec<Vec<CryptoHash>>,
+}
+
+impl MerkleTree {
+    pub fn new(leaves: &[(&AccountId, u128)]) -> anyhow::Result<Self> {
+        let mut level = leaves
+            .iter()
+            .map(|(account_id, amount)| {
+                let mut data = vec![0];
+                data.extend(borsh::to_vec(&(account_id.as_str(), amount))?);
+                Ok(env::sha256_array(&data))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let mut levels = vec![level.clone()];
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(*left, *right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(level.clone());
+        }
+        Ok(Self { levels })
+    }
+
+    pub fn root(&self) -> Base58CryptoHash {
+        self.levels.last().unwrap()[0].into()
+    }
+
+    /// Returns the siblings of the leaf at `index` from bottom to top.
+    pub fn proof(&self, mut index: usize) -> Vec<Base58CryptoHash> {
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push((*sibling).into());
+            }
+            index /= 2;
+        }
+        proof
+    }
+}
+
+fn hash_pair(left: CryptoHash, right: CryptoHash) -> CryptoHash {
+    let (left, right) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+    let mut data = vec![1];
+    data.extend(left);
+    data.extend(right);
+    env::sha256_array(&data)
+}
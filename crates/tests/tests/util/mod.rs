@@ -1,5 +1,7 @@
 pub mod call;
 pub mod event;
+pub mod evm;
+pub mod merkle;
 pub mod view;
 
 use near_sdk::json_types::{Base58CryptoHash, Base64VecU8, U128, U64};
@@ -1,6 +1,13 @@
 use super::log_view_result;
-use near_sdk::json_types::U128;
+use near_contract_standards::{
+    fungible_token::metadata::FungibleTokenMetadata, storage_management::StorageBalanceBounds,
+};
+use near_sdk::{
+    json_types::{U128, U64},
+    NearToken,
+};
 use near_workspaces::{AccountId, Contract};
+use serde::Deserialize;
 
 pub async fn ft_balance_of(contract: &Contract, account_id: &AccountId) -> anyhow::Result<U128> {
     let res = log_view_result(
@@ -18,3 +25,388 @@ pub async fn ft_total_supply(contract: &Contract) -> anyhow::Result<U128> {
     let res = log_view_result(contract.call("ft_total_supply").max_gas().view().await?)?;
     Ok(res.json()?)
 }
+
+pub async fn ft_metadata(contract: &Contract) -> anyhow::Result<FungibleTokenMetadata> {
+    let res = log_view_result(contract.call("ft_metadata").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+pub async fn has_role(
+    contract: &Contract,
+    role: &str,
+    account_id: &AccountId,
+) -> anyhow::Result<bool> {
+    let res = log_view_result(
+        contract
+            .call("has_role")
+            .args_json((role, account_id))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn role_members(contract: &Contract, role: &str) -> anyhow::Result<Vec<AccountId>> {
+    let res = log_view_result(
+        contract
+            .call("role_members")
+            .args_json((role,))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn owner(contract: &Contract) -> anyhow::Result<AccountId> {
+    let res = log_view_result(contract.call("owner").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct PendingOwner {
+    pub account_id: AccountId,
+    pub expires_at: Option<U64>,
+}
+
+pub async fn pending_owner(contract: &Contract) -> anyhow::Result<Option<PendingOwner>> {
+    let res = log_view_result(contract.call("pending_owner").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct PauseStatus {
+    pub transfers: bool,
+    pub mint: bool,
+    pub storage: bool,
+}
+
+pub async fn pause_status(contract: &Contract) -> anyhow::Result<PauseStatus> {
+    let res = log_view_result(contract.call("pause_status").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct MigrationStatus {
+    pub is_open: bool,
+    pub opens_at: U64,
+    pub closes_at: Option<U64>,
+    pub is_finalized: bool,
+}
+
+pub async fn migration_status(contract: &Contract) -> anyhow::Result<MigrationStatus> {
+    let res = log_view_result(contract.call("migration_status").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct MigrationStats {
+    pub total_migrated: U128,
+    pub migration_count: U64,
+    pub max_migratable_supply: Option<U128>,
+}
+
+pub async fn migration_stats(contract: &Contract) -> anyhow::Result<MigrationStats> {
+    let res = log_view_result(contract.call("migration_stats").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+pub async fn migrated_balance_of(
+    contract: &Contract,
+    account_id: &AccountId,
+) -> anyhow::Result<U128> {
+    let res = log_view_result(
+        contract
+            .call("migrated_balance_of")
+            .args_json((account_id,))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn is_migration_processed(
+    contract: &Contract,
+    minter_id: &AccountId,
+    migration_id: U64,
+) -> anyhow::Result<bool> {
+    let res = log_view_result(
+        contract
+            .call("is_migration_processed")
+            .args_json((minter_id, migration_id))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn migrate_address(contract: &Contract) -> anyhow::Result<AccountId> {
+    let res = log_view_result(contract.call("migrate_address").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct Minter {
+    pub account_id: AccountId,
+    pub quota: Option<U128>,
+    pub minted: U128,
+    pub expires_at: Option<U64>,
+    pub enabled: bool,
+    pub rate: ExchangeRate,
+}
+
+#[derive(Deserialize)]
+pub struct ExchangeRate {
+    pub numerator: U128,
+    pub denominator: U128,
+    pub decimals: u8,
+}
+
+pub async fn minter(contract: &Contract, account_id: &AccountId) -> anyhow::Result<Option<Minter>> {
+    let res = log_view_result(
+        contract
+            .call("minter")
+            .args_json((account_id,))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn minters(contract: &Contract) -> anyhow::Result<Vec<Minter>> {
+    let res = log_view_result(contract.call("minters").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct MintRateLimit {
+    pub window: U64,
+    pub limit: Option<U128>,
+    pub window_start: U64,
+    pub window_usage: U128,
+}
+
+pub async fn mint_rate_limit(contract: &Contract) -> anyhow::Result<MintRateLimit> {
+    let res = log_view_result(contract.call("mint_rate_limit").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct StorageSponsorship {
+    pub sponsored_count: U64,
+    pub sponsored_amount: NearToken,
+    pub max_count: Option<U64>,
+    pub max_amount: Option<NearToken>,
+    pub remaining_count: Option<U64>,
+    pub remaining_amount: Option<NearToken>,
+}
+
+pub async fn storage_sponsorship(contract: &Contract) -> anyhow::Result<StorageSponsorship> {
+    let res = log_view_result(
+        contract
+            .call("storage_sponsorship")
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn storage_balance_bounds(contract: &Contract) -> anyhow::Result<StorageBalanceBounds> {
+    let res = log_view_result(
+        contract
+            .call("storage_balance_bounds")
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct LegacyToken {
+    pub token_id: AccountId,
+    pub enabled: bool,
+    pub locked: U128,
+}
+
+pub async fn legacy_tokens(contract: &Contract) -> anyhow::Result<Vec<LegacyToken>> {
+    let res = log_view_result(contract.call("legacy_tokens").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct ClaimAccount {
+    pub address: String,
+    pub balance: U128,
+    pub claimed: U128,
+    pub nonce: U64,
+}
+
+pub async fn claim_account(
+    contract: &Contract,
+    address: &str,
+) -> anyhow::Result<Option<ClaimAccount>> {
+    let res = log_view_result(
+        contract
+            .call("claim_account")
+            .args_json((address,))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn claim_signed_message(
+    contract: &Contract,
+    account_id: &AccountId,
+    amount: u128,
+    nonce: u64,
+    deadline: u64,
+) -> anyhow::Result<String> {
+    let res = log_view_result(
+        contract
+            .call("claim_signed_message")
+            .args_json((account_id, U128(amount), U64(nonce), U64(deadline)))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct AirdropCampaign {
+    pub campaign_id: U64,
+    pub merkle_root: String,
+    pub total_amount: U128,
+    pub claimed_amount: U128,
+    pub expires_at: U64,
+    pub treasury_id: AccountId,
+    pub closed: bool,
+}
+
+pub async fn airdrop_campaign(
+    contract: &Contract,
+    campaign_id: u64,
+) -> anyhow::Result<Option<AirdropCampaign>> {
+    let res = log_view_result(
+        contract
+            .call("airdrop_campaign")
+            .args_json((U64(campaign_id),))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn is_airdrop_claimed(
+    contract: &Contract,
+    campaign_id: u64,
+    account_id: &AccountId,
+    amount: u128,
+) -> anyhow::Result<bool> {
+    let res = log_view_result(
+        contract
+            .call("is_airdrop_claimed")
+            .args_json((U64(campaign_id), account_id, U128(amount)))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct BonusPool {
+    pub balance: U128,
+    pub paid: U128,
+    pub current_bonus_bps: u16,
+}
+
+pub async fn bonus_pool(contract: &Contract) -> anyhow::Result<BonusPool> {
+    let res = log_view_result(contract.call("bonus_pool").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct RecoveryRequest {
+    pub id: U64,
+    pub source_id: AccountId,
+    pub amount: U128,
+    pub destination_id: AccountId,
+    pub reason: String,
+    pub tx_reference: String,
+    pub executable_at: U64,
+}
+
+pub async fn recovery_requests(contract: &Contract) -> anyhow::Result<Vec<RecoveryRequest>> {
+    let res = log_view_result(contract.call("recovery_requests").max_gas().view().await?)?;
+    Ok(res.json()?)
+}
+
+#[derive(Deserialize)]
+pub struct ForeignBalance {
+    pub token_id: AccountId,
+    pub sender_id: AccountId,
+    pub amount: U128,
+}
+
+pub async fn foreign_balances(contract: &Contract) -> anyhow::Result<Vec<ForeignBalance>> {
+    let res = log_view_result(
+        contract
+            .call("foreign_balances")
+            .args_json((None::<U64>, None::<U64>))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn foreign_token_storage_cost(contract: &Contract) -> anyhow::Result<NearToken> {
+    let res = log_view_result(
+        contract
+            .call("foreign_token_storage_cost")
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn ft_locked_balance_of(
+    contract: &Contract,
+    account_id: &AccountId,
+) -> anyhow::Result<U128> {
+    let res = log_view_result(
+        contract
+            .call("ft_locked_balance_of")
+            .args_json((account_id,))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
+
+pub async fn ft_unlocked_balance_of(
+    contract: &Contract,
+    account_id: &AccountId,
+) -> anyhow::Result<U128> {
+    let res = log_view_result(
+        contract
+            .call("ft_unlocked_balance_of")
+            .args_json((account_id,))
+            .max_gas()
+            .view()
+            .await?,
+    )?;
+    Ok(res.json()?)
+}
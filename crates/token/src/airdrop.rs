@@ -0,0 +1,277 @@
+use crate::{
+    event::{AirdropCampaignClosed, AirdropCampaignCreated, AirdropClaimed},
+    pause::PauseCategory,
+    Contract, ContractExt,
+};
+use near_contract_standards::fungible_token::Balance;
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{Base58CryptoHash, U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId, CryptoHash,
+};
+
+/// Distribution of tokens to the leaves of a Merkle tree.
+/// The tokens are held in escrow by the contract account until they are claimed
+/// or returned to `treasury_id` once the campaign has expired.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct AirdropCampaign {
+    pub merkle_root: CryptoHash,
+    pub total_amount: Balance,
+    pub claimed_amount: Balance,
+    pub expires_at: u64,
+    pub treasury_id: AccountId,
+    pub closed: bool,
+}
+
+impl AirdropCampaign {
+    fn is_expired(&self) -> bool {
+        self.expires_at <= env::block_timestamp()
+    }
+
+    fn escrow(&self) -> Balance {
+        if self.closed {
+            0
+        } else {
+            self.total_amount - self.claimed_amount
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AirdropCampaignView {
+    pub campaign_id: U64,
+    pub merkle_root: Base58CryptoHash,
+    pub total_amount: U128,
+    pub claimed_amount: U128,
+    pub expires_at: U64,
+    pub treasury_id: AccountId,
+    pub closed: bool,
+}
+
+pub trait Airdrop {
+    /// Creates an airdrop campaign for the Merkle tree with `merkle_root` and returns its id.
+    /// `total_amount` is taken from the balance of the owner and held in escrow until
+    /// `expires_at` (in nanoseconds). Unclaimed tokens are returned to `treasury_id`.
+    /// Can only be called by the owner.
+    ///
+    /// Leaves are `sha256(0x00 || borsh(account_id, amount))`, inner nodes are
+    /// `sha256(0x01 || min(left, right) || max(left, right))`.
+    fn create_airdrop_campaign(
+        &mut self,
+        merkle_root: Base58CryptoHash,
+        total_amount: U128,
+        expires_at: U64,
+        treasury_id: AccountId,
+    ) -> U64;
+
+    /// Transfers `amount` of the campaign to the predecessor, if the proof shows that
+    /// the leaf of the predecessor and `amount` is part of the Merkle tree.
+    /// Each leaf can only be claimed once.
+    fn claim(&mut self, campaign_id: U64, amount: U128, proof: Vec<Base58CryptoHash>) -> U128;
+
+    /// Returns the unclaimed tokens of an expired campaign to its treasury.
+    /// Can be called by anyone.
+    fn close_airdrop_campaign(&mut self, campaign_id: U64) -> U128;
+
+    fn airdrop_campaign(&self, campaign_id: U64) -> Option<AirdropCampaignView>;
+
+    fn airdrop_campaigns(&self) -> Vec<AirdropCampaignView>;
+
+    /// Returns whether `account_id` has claimed `amount` of the campaign.
+    fn is_airdrop_claimed(&self, campaign_id: U64, account_id: AccountId, amount: U128) -> bool;
+}
+
+#[near_bindgen]
+impl Airdrop for Contract {
+    fn create_airdrop_campaign(
+        &mut self,
+        merkle_root: Base58CryptoHash,
+        total_amount: U128,
+        expires_at: U64,
+        treasury_id: AccountId,
+    ) -> U64 {
+        self.assert_owner();
+        require!(total_amount.0 > 0, "Amount must be positive");
+        require!(
+            expires_at.0 > env::block_timestamp(),
+            "Campaign has to expire in the future"
+        );
+        let self_id = env::current_account_id();
+        require!(treasury_id != self_id, "Treasury can not be the contract");
+        if !self.token.accounts.contains_key(&self_id) {
+            self.token.internal_register_account(&self_id);
+        }
+        let campaign_id = self.airdrop_campaigns.len();
+        self.token.internal_transfer(
+            &env::predecessor_account_id(),
+            &self_id,
+            total_amount.0,
+            Some(format!("Airdrop campaign {}", campaign_id)),
+        );
+        self.airdrop_campaigns.push(&AirdropCampaign {
+            merkle_root: merkle_root.into(),
+            total_amount: total_amount.0,
+            claimed_amount: 0,
+            expires_at: expires_at.0,
+            treasury_id: treasury_id.clone(),
+            closed: false,
+        });
+
+        AirdropCampaignCreated {
+            campaign_id: campaign_id.into(),
+            merkle_root,
+            total_amount,
+            expires_at,
+            treasury_id: &treasury_id,
+        }
+        .emit();
+        campaign_id.into()
+    }
+
+    fn claim(&mut self, campaign_id: U64, amount: U128, proof: Vec<Base58CryptoHash>) -> U128 {
+        self.assert_not_paused(PauseCategory::Transfers);
+        let mut campaign = self.internal_get_airdrop_campaign(campaign_id.0);
+        require!(
+            !campaign.closed && !campaign.is_expired(),
+            "Campaign has expired"
+        );
+        let account_id = env::predecessor_account_id();
+        let leaf = airdrop_leaf(&account_id, amount.0);
+        require!(
+            merkle_root(leaf, &proof) == campaign.merkle_root,
+            "Invalid proof"
+        );
+        require!(
+            amount.0 <= campaign.total_amount - campaign.claimed_amount,
+            "Campaign is exhausted"
+        );
+        require!(
+            self.airdrop_claims.insert(&(campaign_id.0, leaf)),
+            "Airdrop has already been claimed"
+        );
+        campaign.claimed_amount += amount.0;
+        self.airdrop_campaigns.replace(campaign_id.0, &campaign);
+
+        if !self.token.accounts.contains_key(&account_id) {
+            self.internal_register_sponsored_account(&account_id);
+        }
+        self.token.internal_transfer(
+            &env::current_account_id(),
+            &account_id,
+            amount.0,
+            Some(format!("Airdrop campaign {}", campaign_id.0)),
+        );
+        AirdropClaimed {
+            campaign_id,
+            account_id: &account_id,
+            amount,
+        }
+        .emit();
+        amount
+    }
+
+    fn close_airdrop_campaign(&mut self, campaign_id: U64) -> U128 {
+        let mut campaign = self.internal_get_airdrop_campaign(campaign_id.0);
+        require!(!campaign.closed, "Campaign has already been closed");
+        require!(campaign.is_expired(), "Campaign has not expired yet");
+        let amount = campaign.escrow();
+        campaign.closed = true;
+        self.airdrop_campaigns.replace(campaign_id.0, &campaign);
+
+        if amount > 0 {
+            if !self.token.accounts.contains_key(&campaign.treasury_id) {
+                self.internal_register_sponsored_account(&campaign.treasury_id);
+            }
+            self.token.internal_transfer(
+                &env::current_account_id(),
+                &campaign.treasury_id,
+                amount,
+                Some(format!("Airdrop campaign {}", campaign_id.0)),
+            );
+        }
+        AirdropCampaignClosed {
+            campaign_id,
+            treasury_id: &campaign.treasury_id,
+            amount: amount.into(),
+        }
+        .emit();
+        amount.into()
+    }
+
+    fn airdrop_campaign(&self, campaign_id: U64) -> Option<AirdropCampaignView> {
+        self.airdrop_campaigns
+            .get(campaign_id.0)
+            .map(|campaign| airdrop_campaign_view(campaign_id.0, campaign))
+    }
+
+    fn airdrop_campaigns(&self) -> Vec<AirdropCampaignView> {
+        self.airdrop_campaigns
+            .iter()
+            .enumerate()
+            .map(|(campaign_id, campaign)| airdrop_campaign_view(campaign_id as u64, campaign))
+            .collect()
+    }
+
+    fn is_airdrop_claimed(&self, campaign_id: U64, account_id: AccountId, amount: U128) -> bool {
+        self.airdrop_claims
+            .contains(&(campaign_id.0, airdrop_leaf(&account_id, amount.0)))
+    }
+}
+
+impl Contract {
+    /// Tokens of the contract account that are reserved for airdrop campaigns.
+    pub(crate) fn internal_airdrop_escrow(&self) -> Balance {
+        self.airdrop_campaigns
+            .iter()
+            .map(|campaign| campaign.escrow())
+            .sum()
+    }
+
+    fn internal_get_airdrop_campaign(&self, campaign_id: u64) -> AirdropCampaign {
+        self.airdrop_campaigns
+            .get(campaign_id)
+            .unwrap_or_else(|| env::panic_str("Campaign does not exist"))
+    }
+}
+
+fn airdrop_campaign_view(campaign_id: u64, campaign: AirdropCampaign) -> AirdropCampaignView {
+    AirdropCampaignView {
+        campaign_id: campaign_id.into(),
+        merkle_root: campaign.merkle_root.into(),
+        total_amount: campaign.total_amount.into(),
+        claimed_amount: campaign.claimed_amount.into(),
+        expires_at: campaign.expires_at.into(),
+        treasury_id: campaign.treasury_id,
+        closed: campaign.closed,
+    }
+}
+
+fn airdrop_leaf(account_id: &AccountId, amount: Balance) -> CryptoHash {
+    let mut data = vec![0];
+    data.extend(
+        borsh::to_vec(&(account_id, amount))
+            .unwrap_or_else(|_| env::panic_str("Failed to serialize leaf")),
+    );
+    env::sha256_array(&data)
+}
+
+/// Computes the root of the Merkle tree that contains `leaf` with the siblings in `proof`.
+fn merkle_root(leaf: CryptoHash, proof: &[Base58CryptoHash]) -> CryptoHash {
+    proof.iter().fold(leaf, |node, sibling| {
+        let sibling: CryptoHash = (*sibling).into();
+        let (left, right) = if node <= sibling {
+            (node, sibling)
+        } else {
+            (sibling, node)
+        };
+        let mut data = vec![1];
+        data.extend(left);
+        data.extend(right);
+        env::sha256_array(&data)
+    })
+}
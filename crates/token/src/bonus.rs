@@ -0,0 +1,177 @@
+use crate::{
+    event::{BonusPoolUpdate, BonusScheduleUpdate},
+    Contract, ContractExt,
+};
+use near_contract_standards::fungible_token::Balance;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+const MAX_BONUS_BPS: u16 = 10_000;
+
+/// Bonus for migrations until `until` (timestamp in nanoseconds) in basis points.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BonusTier {
+    pub until: U64,
+    pub bonus_bps: u16,
+}
+
+/// Tokens held by the contract account, which are paid out as bonus on top of migrated tokens.
+/// The bonus decays with the tiers of `schedule` and stops once the pool is empty.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Default, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct BonusPool {
+    pub balance: Balance,
+    pub paid: Balance,
+    pub schedule: Vec<BonusTier>,
+}
+
+impl BonusPool {
+    fn current_bonus_bps(&self) -> u16 {
+        let now = env::block_timestamp();
+        self.schedule
+            .iter()
+            .find(|tier| now < tier.until.0)
+            .map(|tier| tier.bonus_bps)
+            .unwrap_or_default()
+    }
+
+    /// Bonus for migrating `amount` right now, limited by the pool balance.
+    fn bonus(&self, amount: Balance) -> Balance {
+        let bps = Balance::from(self.current_bonus_bps());
+        let max_bps = Balance::from(MAX_BONUS_BPS);
+        // can't overflow, since `bps` is at most `MAX_BONUS_BPS`
+        let bonus = amount / max_bps * bps + amount % max_bps * bps / max_bps;
+        bonus.min(self.balance)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BonusPoolView {
+    pub balance: U128,
+    pub paid: U128,
+    pub current_bonus_bps: u16,
+    pub schedule: Vec<BonusTier>,
+}
+
+pub trait MigrationBonus {
+    fn bonus_pool(&self) -> BonusPoolView;
+
+    /// Sets the tiers of the bonus, which have to be ordered by `until` with a decaying
+    /// `bonus_bps`. Can only be called by the owner.
+    fn set_bonus_schedule(&mut self, schedule: Vec<BonusTier>);
+
+    /// Moves `amount` from the balance of the owner into the bonus pool.
+    /// Can only be called by the owner.
+    fn fund_bonus_pool(&mut self, amount: U128);
+
+    /// Moves `amount` from the bonus pool back to the owner.
+    /// Can only be called by the owner.
+    fn withdraw_bonus_pool(&mut self, amount: U128);
+}
+
+#[near_bindgen]
+impl MigrationBonus for Contract {
+    fn bonus_pool(&self) -> BonusPoolView {
+        BonusPoolView {
+            balance: self.bonus_pool.balance.into(),
+            paid: self.bonus_pool.paid.into(),
+            current_bonus_bps: self.bonus_pool.current_bonus_bps(),
+            schedule: self.bonus_pool.schedule.clone(),
+        }
+    }
+
+    fn set_bonus_schedule(&mut self, schedule: Vec<BonusTier>) {
+        self.assert_owner();
+        require!(
+            schedule.iter().all(|tier| tier.bonus_bps <= MAX_BONUS_BPS),
+            "Bonus can not exceed 100%"
+        );
+        require!(
+            schedule
+                .windows(2)
+                .all(|tiers| tiers[0].until.0 < tiers[1].until.0
+                    && tiers[0].bonus_bps >= tiers[1].bonus_bps),
+            "Bonus tiers have to be ordered and decaying"
+        );
+        self.bonus_pool.schedule = schedule;
+
+        BonusScheduleUpdate {
+            schedule: &self.bonus_pool.schedule,
+        }
+        .emit();
+    }
+
+    fn fund_bonus_pool(&mut self, amount: U128) {
+        self.assert_owner();
+        let self_id = env::current_account_id();
+        if !self.token.accounts.contains_key(&self_id) {
+            self.token.internal_register_account(&self_id);
+        }
+        self.token.internal_transfer(
+            &env::predecessor_account_id(),
+            &self_id,
+            amount.0,
+            Some("Bonus pool".to_string()),
+        );
+        self.bonus_pool.balance += amount.0;
+
+        BonusPoolUpdate {
+            balance: self.bonus_pool.balance.into(),
+        }
+        .emit();
+    }
+
+    fn withdraw_bonus_pool(&mut self, amount: U128) {
+        self.assert_owner();
+        require!(
+            amount.0 <= self.bonus_pool.balance,
+            "Amount exceeds the bonus pool"
+        );
+        self.bonus_pool.balance -= amount.0;
+        self.token.internal_transfer(
+            &env::current_account_id(),
+            &env::predecessor_account_id(),
+            amount.0,
+            Some("Bonus pool".to_string()),
+        );
+
+        BonusPoolUpdate {
+            balance: self.bonus_pool.balance.into(),
+        }
+        .emit();
+    }
+}
+
+impl Contract {
+    /// Pays the bonus for migrating `amount` to `account_id` and returns it.
+    pub(crate) fn internal_pay_migration_bonus(
+        &mut self,
+        account_id: &AccountId,
+        amount: Balance,
+    ) -> Balance {
+        let self_id = env::current_account_id();
+        let bonus = self.bonus_pool.bonus(amount);
+        if bonus > 0 && account_id != &self_id {
+            self.bonus_pool.balance -= bonus;
+            self.bonus_pool.paid += bonus;
+            self.token.internal_transfer(
+                &self_id,
+                account_id,
+                bonus,
+                Some("Migration bonus".to_string()),
+            );
+            bonus
+        } else {
+            0
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{event::ClaimSnapshotUpdate, evm, pause::PauseCategory, Contract, ContractExt};
+use near_contract_standards::fungible_token::Balance;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId, PromiseOrValue,
+};
+
+/// SHITZUv1 balance of an Aurora address that can be claimed with a signature of its key.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Default, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ClaimAccount {
+    pub balance: Balance,
+    pub claimed: Balance,
+    /// Nonce of the next claim, so that signatures can not be replayed.
+    pub nonce: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimSnapshotEntry {
+    /// 0x-prefixed EVM address.
+    pub address: String,
+    pub balance: U128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimAccountView {
+    pub address: String,
+    pub balance: U128,
+    pub claimed: U128,
+    pub nonce: U64,
+}
+
+pub trait SignedClaim {
+    /// Publishes SHITZUv1 balances of Aurora addresses, which can then be claimed
+    /// via `claim_signed`. Balances of addresses that are already part of the snapshot
+    /// are replaced. Claims and migrations through the bridge are mutually exclusive:
+    /// claimed SHITZUv1 can still be moved on Aurora, so once a snapshot has been published
+    /// the migrate address can no longer mint and the snapshot has to cover all balances
+    /// that have not been migrated by then. Migrations of other minters on behalf of
+    /// an Aurora sender are charged against its balance. Can only be called by the owner.
+    fn set_claim_snapshot(&mut self, entries: Vec<ClaimSnapshotEntry>);
+
+    fn claim_account(&self, address: String) -> Option<ClaimAccountView>;
+
+    /// Returns the message that has to be signed via `personal_sign` to claim `amount`
+    /// for `account_id`. `deadline` is a timestamp in nanoseconds.
+    fn claim_signed_message(
+        &self,
+        account_id: AccountId,
+        amount: U128,
+        nonce: U64,
+        deadline: U64,
+    ) -> String;
+
+    /// Mints `amount` from the snapshot balance of the Aurora address that has signed
+    /// `claim_signed_message` to `account_id`. Can be submitted by anyone, so that holders
+    /// don't need to pay for gas. `signature` is 0x-prefixed hex encoded `r || s || v`.
+    /// `amount` is converted with the exchange rate of the migrate address like migrations
+    /// through the bridge. Returns the minted amount.
+    fn claim_signed(
+        &mut self,
+        account_id: AccountId,
+        amount: U128,
+        nonce: U64,
+        deadline: U64,
+        signature: String,
+    ) -> PromiseOrValue<U128>;
+}
+
+#[near_bindgen]
+impl SignedClaim for Contract {
+    fn set_claim_snapshot(&mut self, entries: Vec<ClaimSnapshotEntry>) {
+        self.assert_owner();
+        self.claim_snapshot_published = true;
+        let addresses: Vec<_> = entries
+            .iter()
+            .map(|entry| {
+                let address = evm::parse_address(&entry.address);
+                let mut claim_account = self.claim_accounts.get(&address).unwrap_or_default();
+                require!(
+                    entry.balance.0 >= claim_account.claimed,
+                    "Balance is lower than the already claimed amount"
+                );
+                claim_account.balance = entry.balance.0;
+                self.claim_accounts.insert(&address, &claim_account);
+                evm::encode_address(&address)
+            })
+            .collect();
+
+        let updates: Vec<_> = addresses
+            .iter()
+            .zip(&entries)
+            .map(|(address, entry)| ClaimSnapshotUpdate {
+                address,
+                balance: entry.balance,
+            })
+            .collect();
+        ClaimSnapshotUpdate::emit_many(&updates);
+    }
+
+    fn claim_account(&self, address: String) -> Option<ClaimAccountView> {
+        let address = evm::parse_address(&address);
+        self.claim_accounts
+            .get(&address)
+            .map(|claim_account| ClaimAccountView {
+                address: evm::encode_address(&address),
+                balance: claim_account.balance.into(),
+                claimed: claim_account.claimed.into(),
+                nonce: claim_account.nonce.into(),
+            })
+    }
+
+    fn claim_signed_message(
+        &self,
+        account_id: AccountId,
+        amount: U128,
+        nonce: U64,
+        deadline: U64,
+    ) -> String {
+        format!(
+            "Claim {} SHITZU for {} on {}\nNonce: {}\nDeadline: {}",
+            amount.0,
+            account_id,
+            env::current_account_id(),
+            nonce.0,
+            deadline.0
+        )
+    }
+
+    fn claim_signed(
+        &mut self,
+        account_id: AccountId,
+        amount: U128,
+        nonce: U64,
+        deadline: U64,
+        signature: String,
+    ) -> PromiseOrValue<U128> {
+        self.assert_not_paused(PauseCategory::Mint);
+        self.assert_migration_open();
+        require!(env::block_timestamp() <= deadline.0, "Claim has expired");
+        require!(amount.0 > 0, "Amount must be positive");
+        let message = self.claim_signed_message(account_id.clone(), amount, nonce, deadline);
+        let address = evm::recover_personal_signer(&message, &signature)
+            .unwrap_or_else(|| env::panic_str("Invalid signature"));
+        let mut claim_account = self
+            .claim_accounts
+            .get(&address)
+            .unwrap_or_else(|| env::panic_str("Address is not part of the claim snapshot"));
+        require!(nonce.0 == claim_account.nonce, "Invalid nonce");
+        require!(
+            amount.0 <= claim_account.balance - claim_account.claimed,
+            "Amount exceeds the claimable balance"
+        );
+        let minted = self.internal_convert_minter_amount(&self.migrate_address, amount.0);
+        self.internal_assert_can_deposit_migration(&account_id, minted);
+        if !self.internal_consume_mint_rate_limit(minted) {
+            return PromiseOrValue::Promise(
+                Self::ext(env::current_account_id()).on_mint_rate_limit_exceeded(),
+            );
+        }
+
+        claim_account.claimed += amount.0;
+        claim_account.nonce += 1;
+        self.claim_accounts.insert(&address, &claim_account);
+        self.internal_deposit_migration(&account_id, minted, None, Some(address));
+        PromiseOrValue::Value(minted.into())
+    }
+}
+
+impl Contract {
+    /// Panics if `minter_id` is the migrate address and the bridge has been replaced
+    /// by a claim snapshot.
+    pub(crate) fn internal_assert_bridge_open(&self, minter_id: &AccountId) {
+        require!(
+            !self.claim_snapshot_published || minter_id != &self.migrate_address,
+            "Bridge migrations are closed by the claim snapshot"
+        );
+    }
+
+    /// Returns the claim account of `address` after `amount` of its SHITZUv1 has been migrated
+    /// by a minter, which reduces its claimable balance, so that the same tokens can not
+    /// be minted twice. Once tokens have been claimed by signature, only the remaining
+    /// claimable balance can be migrated from the address.
+    pub(crate) fn internal_charged_claim_account(
+        &self,
+        address: &[u8; 20],
+        amount: Balance,
+    ) -> Option<ClaimAccount> {
+        let mut claim_account = self.claim_accounts.get(address)?;
+        let claimable = claim_account.balance - claim_account.claimed;
+        require!(
+            claim_account.nonce == 0 || amount <= claimable,
+            "Amount exceeds the claimable balance of the Aurora sender"
+        );
+        claim_account.claimed += amount.min(claimable);
+        Some(claim_account)
+    }
+}
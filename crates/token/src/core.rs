@@ -1,11 +1,13 @@
-use crate::{Contract, ContractExt};
+use crate::{pause::PauseCategory, Contract, ContractExt};
 use near_contract_standards::fungible_token::FungibleTokenCore;
-use near_sdk::{json_types::U128, near_bindgen, AccountId, PromiseOrValue};
+use near_sdk::{env, json_types::U128, near_bindgen, AccountId, PromiseOrValue};
 
 #[near_bindgen]
 impl FungibleTokenCore for Contract {
     #[payable]
     fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>) {
+        self.assert_not_paused(PauseCategory::Transfers);
+        self.internal_assert_unlocked(&env::predecessor_account_id(), amount.0);
         self.token.ft_transfer(receiver_id, amount, memo)
     }
 
@@ -17,6 +19,8 @@ impl FungibleTokenCore for Contract {
         memo: Option<String>,
         msg: String,
     ) -> PromiseOrValue<U128> {
+        self.assert_not_paused(PauseCategory::Transfers);
+        self.internal_assert_unlocked(&env::predecessor_account_id(), amount.0);
         self.token.ft_transfer_call(receiver_id, amount, memo, msg)
     }
 
@@ -0,0 +1,607 @@
+//! Shitzu specific events.
+//!
+//! These events extend the events format (nep-297) with the `shitzu` standard:
+//! <https://github.com/near/NEPs/blob/master/specs/Standards/EventsFormat.md>
+//!
+//! Like the nep141 events, every event can be logged by calling `.emit()` on it.
+
+use crate::{
+    bonus::BonusTier, lock::MintLock, minter::ExchangeRate, pause::PauseCategory, role::Role,
+};
+use near_sdk::{
+    env,
+    json_types::{Base58CryptoHash, Base64VecU8, U128, U64},
+    serde::Serialize,
+    serde_json, AccountIdRef, NearToken,
+};
+
+/// Data to log when the token metadata has been updated.
+/// Wallets and indexers should refetch `ft_metadata` when seeing this event.
+/// The icon is not part of the event in order to keep the log small.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtMetadataUpdate<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference_hash: Option<&'a Base64VecU8>,
+}
+
+impl FtMetadataUpdate<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::FtMetadataUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when a role has been granted to an account.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleGranted<'a> {
+    pub role: Role,
+    pub account_id: &'a AccountIdRef,
+    pub by: &'a AccountIdRef,
+}
+
+impl RoleGranted<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::RoleGranted(&[self])).emit()
+    }
+}
+
+/// Data to log when a role has been revoked from or renounced by an account.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoleRevoked<'a> {
+    pub role: Role,
+    pub account_id: &'a AccountIdRef,
+    pub by: &'a AccountIdRef,
+}
+
+impl RoleRevoked<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::RoleRevoked(&[self])).emit()
+    }
+}
+
+/// Data to log when a new owner has been proposed.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OwnershipTransferProposed<'a> {
+    pub owner_id: &'a AccountIdRef,
+    pub pending_owner_id: &'a AccountIdRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<U64>,
+}
+
+impl OwnershipTransferProposed<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::OwnershipTransferProposed(&[self])).emit()
+    }
+}
+
+/// Data to log when the pending owner has accepted the ownership.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OwnershipTransferred<'a> {
+    pub old_owner_id: &'a AccountIdRef,
+    pub new_owner_id: &'a AccountIdRef,
+}
+
+impl OwnershipTransferred<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::OwnershipTransferred(&[self])).emit()
+    }
+}
+
+/// Data to log when a pending ownership transfer has been cancelled.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct OwnershipTransferCancelled<'a> {
+    pub owner_id: &'a AccountIdRef,
+    pub pending_owner_id: &'a AccountIdRef,
+}
+
+impl OwnershipTransferCancelled<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::OwnershipTransferCancelled(&[self])).emit()
+    }
+}
+
+/// Data to log when contract functionality has been paused.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Paused<'a> {
+    pub categories: &'a [PauseCategory],
+    pub by: &'a AccountIdRef,
+}
+
+impl Paused<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::Paused(&[self])).emit()
+    }
+}
+
+/// Data to log when contract functionality has been unpaused.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Unpaused<'a> {
+    pub categories: &'a [PauseCategory],
+    pub by: &'a AccountIdRef,
+}
+
+impl Unpaused<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::Unpaused(&[self])).emit()
+    }
+}
+
+/// Data to log when the migration window has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrationWindowUpdate {
+    pub opens_at: U64,
+    pub closes_at: Option<U64>,
+}
+
+impl MigrationWindowUpdate {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MigrationWindowUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when a closed migration has been reopened.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrationReopened<'a> {
+    pub opens_at: U64,
+    pub closes_at: Option<U64>,
+    pub by: &'a AccountIdRef,
+}
+
+impl MigrationReopened<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MigrationReopened(&[self])).emit()
+    }
+}
+
+/// Data to log when the migration has been finalized and minting has permanently been disabled.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrationFinalized<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub treasury_id: Option<&'a AccountIdRef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<U128>,
+    pub by: &'a AccountIdRef,
+}
+
+impl MigrationFinalized<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MigrationFinalized(&[self])).emit()
+    }
+}
+
+/// Data to log when the max migratable supply has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MaxMigratableSupplyUpdate {
+    pub max_migratable_supply: Option<U128>,
+}
+
+impl MaxMigratableSupplyUpdate {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MaxMigratableSupplyUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when tokens have been minted for a migration from SHITZUv1.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ShitzuMigrate<'a> {
+    pub account_id: &'a AccountIdRef,
+    pub amount: U128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aurora_sender: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub migration_id: Option<U64>,
+    /// Tokens that have been paid from the bonus pool on top of `amount`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bonus: Option<U128>,
+    /// Part of `amount` that has been locked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locked: Option<U128>,
+}
+
+impl ShitzuMigrate<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::ShitzuMigrate(&[self])).emit()
+    }
+}
+
+/// Data to log when the migration contract on Aurora has been replaced.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrateAddressUpdate<'a> {
+    pub old_migrate_address: &'a AccountIdRef,
+    pub new_migrate_address: &'a AccountIdRef,
+}
+
+impl MigrateAddressUpdate<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MigrateAddressUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when the configuration of a minter has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MinterUpdate<'a> {
+    pub account_id: &'a AccountIdRef,
+    pub quota: Option<U128>,
+    pub expires_at: Option<U64>,
+    pub enabled: bool,
+    pub rate: &'a ExchangeRate,
+}
+
+impl MinterUpdate<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MinterUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when the mint rate limit has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MintRateLimitUpdate {
+    pub window: U64,
+    pub limit: Option<U128>,
+}
+
+impl MintRateLimitUpdate {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MintRateLimitUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when a mint has been rejected because it would exceed the mint rate limit.
+/// Minting is paused at the same time.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MintRateLimitExceeded {
+    pub amount: U128,
+    pub window_usage: U128,
+    pub limit: U128,
+}
+
+impl MintRateLimitExceeded {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MintRateLimitExceeded(&[self])).emit()
+    }
+}
+
+/// Data to log when the budget for sponsored storage registrations has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageSponsorshipBudgetUpdate {
+    pub max_count: Option<U64>,
+    pub max_amount: Option<NearToken>,
+}
+
+impl StorageSponsorshipBudgetUpdate {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::StorageSponsorshipBudgetUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when swapping a legacy token has been allowed or disallowed.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LegacyTokenUpdate<'a> {
+    pub token_id: &'a AccountIdRef,
+    pub enabled: bool,
+}
+
+impl LegacyTokenUpdate<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::LegacyTokenUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when balances of the claim snapshot have been published.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimSnapshotUpdate<'a> {
+    pub address: &'a str,
+    pub balance: U128,
+}
+
+impl ClaimSnapshotUpdate<'_> {
+    /// Logs all updates of a snapshot batch within a single event.
+    pub fn emit_many(updates: &[Self]) {
+        new_shitzu_v1(ShitzuEventKind::ClaimSnapshotUpdate(updates)).emit()
+    }
+}
+
+/// Data to log when an airdrop campaign has been created.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AirdropCampaignCreated<'a> {
+    pub campaign_id: U64,
+    pub merkle_root: Base58CryptoHash,
+    pub total_amount: U128,
+    pub expires_at: U64,
+    pub treasury_id: &'a AccountIdRef,
+}
+
+impl AirdropCampaignCreated<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::AirdropCampaignCreated(&[self])).emit()
+    }
+}
+
+/// Data to log when tokens of an airdrop campaign have been claimed.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AirdropClaimed<'a> {
+    pub campaign_id: U64,
+    pub account_id: &'a AccountIdRef,
+    pub amount: U128,
+}
+
+impl AirdropClaimed<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::AirdropClaimed(&[self])).emit()
+    }
+}
+
+/// Data to log when an expired airdrop campaign has been closed
+/// and its unclaimed tokens have been returned to the treasury.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AirdropCampaignClosed<'a> {
+    pub campaign_id: U64,
+    pub treasury_id: &'a AccountIdRef,
+    pub amount: U128,
+}
+
+impl AirdropCampaignClosed<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::AirdropCampaignClosed(&[self])).emit()
+    }
+}
+
+/// Data to log when the schedule of the migration bonus has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BonusScheduleUpdate<'a> {
+    pub schedule: &'a [BonusTier],
+}
+
+impl BonusScheduleUpdate<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::BonusScheduleUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when the bonus pool has been funded or withdrawn from.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BonusPoolUpdate {
+    pub balance: U128,
+}
+
+impl BonusPoolUpdate {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::BonusPoolUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when the lock of migrated tokens has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MintLockUpdate<'a> {
+    pub mint_lock: Option<&'a MintLock>,
+}
+
+impl MintLockUpdate<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::MintLockUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when a recovery of tokens has been filed.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecoveryFiled<'a> {
+    pub id: U64,
+    pub source_id: &'a AccountIdRef,
+    pub amount: U128,
+    pub destination_id: &'a AccountIdRef,
+    pub reason: &'a str,
+    pub tx_reference: &'a str,
+    pub executable_at: U64,
+}
+
+impl RecoveryFiled<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::RecoveryFiled(&[self])).emit()
+    }
+}
+
+/// Data to log when a recovery of tokens has been executed.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecoveryExecuted<'a> {
+    pub id: U64,
+    pub source_id: &'a AccountIdRef,
+    pub amount: U128,
+    pub destination_id: &'a AccountIdRef,
+    pub by: &'a AccountIdRef,
+}
+
+impl RecoveryExecuted<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::RecoveryExecuted(&[self])).emit()
+    }
+}
+
+/// Data to log when a pending recovery of tokens has been cancelled.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecoveryCancelled {
+    pub id: U64,
+}
+
+impl RecoveryCancelled {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::RecoveryCancelled(&[self])).emit()
+    }
+}
+
+/// Data to log when the timelock of recoveries has been updated.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecoveryTimelockUpdate {
+    pub timelock: U64,
+}
+
+impl RecoveryTimelockUpdate {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::RecoveryTimelockUpdate(&[self])).emit()
+    }
+}
+
+/// Data to log when tokens of another NEP-141 contract have been received.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ForeignTokenReceived<'a> {
+    pub token_id: &'a AccountIdRef,
+    pub sender_id: &'a AccountIdRef,
+    pub amount: U128,
+}
+
+impl ForeignTokenReceived<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::ForeignTokenReceived(&[self])).emit()
+    }
+}
+
+/// Data to log when tokens of another NEP-141 contract have been returned to their sender.
+#[must_use]
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ForeignTokenReturned<'a> {
+    pub token_id: &'a AccountIdRef,
+    pub sender_id: &'a AccountIdRef,
+    pub amount: U128,
+}
+
+impl ForeignTokenReturned<'_> {
+    pub fn emit(self) {
+        new_shitzu_v1(ShitzuEventKind::ForeignTokenReturned(&[self])).emit()
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "standard")]
+#[serde(rename_all = "snake_case")]
+#[must_use = "don't forget to `.emit()` this event"]
+enum NearEvent<'a> {
+    Shitzu(ShitzuEvent<'a>),
+}
+
+impl NearEvent<'_> {
+    fn emit(self) {
+        // Events cannot fail to serialize so fine to panic on error
+        let json = serde_json::to_string(&self)
+            .ok()
+            .unwrap_or_else(|| env::abort());
+        env::log_str(&format!("EVENT_JSON:{}", json));
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+struct ShitzuEvent<'a> {
+    version: &'static str,
+    #[serde(flatten)]
+    event_kind: ShitzuEventKind<'a>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+enum ShitzuEventKind<'a> {
+    FtMetadataUpdate(&'a [FtMetadataUpdate<'a>]),
+    RoleGranted(&'a [RoleGranted<'a>]),
+    RoleRevoked(&'a [RoleRevoked<'a>]),
+    OwnershipTransferProposed(&'a [OwnershipTransferProposed<'a>]),
+    OwnershipTransferred(&'a [OwnershipTransferred<'a>]),
+    OwnershipTransferCancelled(&'a [OwnershipTransferCancelled<'a>]),
+    Paused(&'a [Paused<'a>]),
+    Unpaused(&'a [Unpaused<'a>]),
+    MigrationWindowUpdate(&'a [MigrationWindowUpdate]),
+    MigrationReopened(&'a [MigrationReopened<'a>]),
+    MigrationFinalized(&'a [MigrationFinalized<'a>]),
+    MaxMigratableSupplyUpdate(&'a [MaxMigratableSupplyUpdate]),
+    ShitzuMigrate(&'a [ShitzuMigrate<'a>]),
+    MigrateAddressUpdate(&'a [MigrateAddressUpdate<'a>]),
+    MinterUpdate(&'a [MinterUpdate<'a>]),
+    MintRateLimitUpdate(&'a [MintRateLimitUpdate]),
+    MintRateLimitExceeded(&'a [MintRateLimitExceeded]),
+    StorageSponsorshipBudgetUpdate(&'a [StorageSponsorshipBudgetUpdate]),
+    LegacyTokenUpdate(&'a [LegacyTokenUpdate<'a>]),
+    ClaimSnapshotUpdate(&'a [ClaimSnapshotUpdate<'a>]),
+    AirdropCampaignCreated(&'a [AirdropCampaignCreated<'a>]),
+    AirdropClaimed(&'a [AirdropClaimed<'a>]),
+    AirdropCampaignClosed(&'a [AirdropCampaignClosed<'a>]),
+    BonusScheduleUpdate(&'a [BonusScheduleUpdate<'a>]),
+    BonusPoolUpdate(&'a [BonusPoolUpdate]),
+    MintLockUpdate(&'a [MintLockUpdate<'a>]),
+    RecoveryFiled(&'a [RecoveryFiled<'a>]),
+    RecoveryExecuted(&'a [RecoveryExecuted<'a>]),
+    RecoveryCancelled(&'a [RecoveryCancelled]),
+    RecoveryTimelockUpdate(&'a [RecoveryTimelockUpdate]),
+    ForeignTokenReceived(&'a [ForeignTokenReceived<'a>]),
+    ForeignTokenReturned(&'a [ForeignTokenReturned<'a>]),
+}
+
+fn new_shitzu_v1(event_kind: ShitzuEventKind) -> NearEvent {
+    NearEvent::Shitzu(ShitzuEvent {
+        version: "1.0.0",
+        event_kind,
+    })
+}
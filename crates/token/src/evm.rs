@@ -0,0 +1,81 @@
+use near_sdk::{env, require, AccountId};
+
+/// Parses a 0x-prefixed hex encoded EVM address.
+pub fn parse_address(address: &str) -> [u8; 20] {
+    decode_hex(address, "EVM address")
+}
+
+/// Recovers the EVM address that has signed `message` via `personal_sign` (EIP-191).
+/// `signature` is 0x-prefixed hex encoded `r || s || v`.
+/// Returns `None` if the signature is invalid.
+pub fn recover_personal_signer(message: &str, signature: &str) -> Option<[u8; 20]> {
+    let signature: [u8; 65] = decode_hex(signature, "Signature");
+    let v = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        _ => return None,
+    };
+    let hash = env::keccak256_array(
+        format!("\x19Ethereum Signed Message:\n{}{}", message.len(), message).as_bytes(),
+    );
+    let public_key = env::ecrecover(&hash, &signature[..64], v, true)?;
+    let mut address = [0; 20];
+    address.copy_from_slice(&env::keccak256_array(&public_key)[12..]);
+    Some(address)
+}
+
+/// Encodes an EVM address as 0x-prefixed lowercase hex.
+pub fn encode_address(address: &[u8; 20]) -> String {
+    format!("0x{}", encode_hex(address))
+}
+
+/// Panics if `account_id` is a top-level account starting with `0x`, but not a valid
+/// ETH-implicit account, which is the 0x-prefixed lowercase hex encoded EVM address.
+pub fn assert_valid_recipient(account_id: &AccountId) {
+    let account_id = account_id.as_str();
+    if let Some(hex) = account_id.strip_prefix("0x") {
+        if !account_id.contains('.') {
+            require!(
+                hex.len() == 40 && hex.bytes().all(|byte| byte.is_ascii_hexdigit()),
+                "Invalid ETH-implicit account ID"
+            );
+        }
+    }
+}
+
+/// Returns the NEAR account of a contract deployed at `address` on the Aurora engine `engine_id`.
+/// This is the predecessor of all NEAR calls made by that contract.
+pub fn aurora_representative_id(address: &[u8; 20], engine_id: &AccountId) -> AccountId {
+    format!("{}.{}", encode_hex(address), engine_id)
+        .parse()
+        .unwrap_or_else(|_| env::panic_str("Invalid Aurora engine account ID"))
+}
+
+fn decode_hex<const N: usize>(value: &str, name: &str) -> [u8; N] {
+    let hex = value
+        .strip_prefix("0x")
+        .unwrap_or_else(|| env::panic_str(&format!("{} has to be 0x-prefixed", name)));
+    if hex.len() != N * 2 {
+        env::panic_str(&format!("{} has to be {} bytes long", name, N));
+    }
+    // `from_str_radix` would accept a leading `+` and slicing panics within multi-byte chars
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        env::panic_str(&format!("{} is not hex encoded", name));
+    }
+    let mut res = [0; N];
+    for (i, byte) in res.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .unwrap_or_else(|_| env::panic_str(&format!("{} is not hex encoded", name)));
+    }
+    res
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut res = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        res.push(HEX[(byte >> 4) as usize] as char);
+        res.push(HEX[(byte & 0xf) as usize] as char);
+    }
+    res
+}
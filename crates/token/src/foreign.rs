@@ -0,0 +1,232 @@
+use crate::{
+    event::{ForeignTokenReceived, ForeignTokenReturned},
+    Contract, ContractExt,
+};
+use near_contract_standards::fungible_token::{core::ext_ft_core, Balance};
+use near_sdk::{
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId, Gas, NearToken, Promise, PromiseResult,
+};
+
+const GAS_FOR_FT_TRANSFER: Gas = Gas::from_tgas(10);
+const GAS_FOR_RESOLVE_WITHDRAWAL: Gas = Gas::from_tgas(5);
+const MAX_FOREIGN_TOKENS_PER_SENDER: u32 = 10;
+
+/// Size of a `foreign_balances` entry and the count of its sender for the longest possible
+/// account IDs, including the overhead of the four storage records.
+const MAX_FOREIGN_BALANCE_BYTES: u64 =
+    4 * 40 + (2 + 2 * (4 + 64) + 8) + (2 + 8 + 2 * (4 + 64)) + (2 + 8 + 16) + (1 + 4 + 64 + 4);
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ForeignBalanceView {
+    pub token_id: AccountId,
+    pub sender_id: AccountId,
+    pub amount: U128,
+}
+
+pub trait ForeignTokens {
+    /// Registers the predecessor, so that tokens of `token_id` it sends to this contract
+    /// are kept until they are withdrawn instead of being refunded. Requires a deposit of
+    /// `foreign_token_storage_cost`, the rest of the attached deposit is refunded.
+    /// Up to 10 tokens can be registered per sender.
+    fn register_foreign_token(&mut self, token_id: AccountId);
+
+    /// Returns the deposit that is required to register a foreign token.
+    fn foreign_token_storage_cost(&self) -> NearToken;
+
+    /// Returns all tokens of `token_id` that have been sent by `sender_id` back to it.
+    /// Once they have been returned, the registration is removed and its deposit is refunded
+    /// to `sender_id`. Tokens sent while the withdrawal is pending are refunded.
+    /// Can only be called by the owner or `sender_id`.
+    fn withdraw_foreign_token(&mut self, token_id: AccountId, sender_id: AccountId) -> Promise;
+
+    /// Credits the withdrawn tokens back if the transfer has failed,
+    /// e.g. because `sender_id` has unregistered from `token_id` in the meantime.
+    fn on_foreign_token_withdrawn(
+        &mut self,
+        token_id: AccountId,
+        sender_id: AccountId,
+        amount: U128,
+    ) -> U128;
+
+    /// Lists the tokens of other NEP-141 contracts that are held by this contract.
+    fn foreign_balances(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<U64>,
+    ) -> Vec<ForeignBalanceView>;
+}
+
+#[near_bindgen]
+impl ForeignTokens for Contract {
+    #[payable]
+    fn register_foreign_token(&mut self, token_id: AccountId) {
+        require!(
+            token_id != env::current_account_id(),
+            "Token can not be registered"
+        );
+        let sender_id = env::predecessor_account_id();
+        let key = (token_id, sender_id);
+        require!(
+            self.foreign_balances.get(&key).is_none(),
+            "Token is already registered"
+        );
+        let (token_id, sender_id) = key;
+        let count = self
+            .foreign_token_counts
+            .get(&sender_id)
+            .unwrap_or_default();
+        require!(
+            count < MAX_FOREIGN_TOKENS_PER_SENDER,
+            "Too many registered foreign tokens"
+        );
+        let amount = env::attached_deposit();
+        let cost = self.foreign_token_storage_cost();
+        require!(
+            amount >= cost,
+            "The attached deposit is less than the foreign token storage cost"
+        );
+
+        self.foreign_token_counts.insert(&sender_id, &(count + 1));
+        self.foreign_balances
+            .insert(&(token_id, sender_id.clone()), &0);
+        let refund = amount.saturating_sub(cost);
+        if !refund.is_zero() {
+            Promise::new(sender_id).transfer(refund);
+        }
+    }
+
+    fn foreign_token_storage_cost(&self) -> NearToken {
+        env::storage_byte_cost().saturating_mul(MAX_FOREIGN_BALANCE_BYTES.into())
+    }
+
+    fn withdraw_foreign_token(&mut self, token_id: AccountId, sender_id: AccountId) -> Promise {
+        let predecessor_id = env::predecessor_account_id();
+        require!(
+            predecessor_id == self.owner || predecessor_id == sender_id,
+            "Only the owner or the sender can withdraw tokens"
+        );
+        let key = (token_id, sender_id);
+        // the registration is removed while the withdrawal is pending
+        let amount = self
+            .foreign_balances
+            .remove(&key)
+            .unwrap_or_else(|| env::panic_str("Token is not registered by the sender"));
+        let (token_id, sender_id) = key;
+        if amount == 0 {
+            return self.internal_release_foreign_token(sender_id);
+        }
+
+        ext_ft_core::ext(token_id.clone())
+            .with_attached_deposit(NearToken::from_yoctonear(1))
+            .with_static_gas(GAS_FOR_FT_TRANSFER)
+            .ft_transfer(
+                sender_id.clone(),
+                amount.into(),
+                Some("Return of tokens sent by mistake".to_string()),
+            )
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_RESOLVE_WITHDRAWAL)
+                    .on_foreign_token_withdrawn(token_id, sender_id, amount.into()),
+            )
+    }
+
+    #[private]
+    fn on_foreign_token_withdrawn(
+        &mut self,
+        token_id: AccountId,
+        sender_id: AccountId,
+        amount: U128,
+    ) -> U128 {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            ForeignTokenReturned {
+                token_id: &token_id,
+                sender_id: &sender_id,
+                amount,
+            }
+            .emit();
+            self.internal_release_foreign_token(sender_id);
+            amount
+        } else {
+            let key = (token_id, sender_id);
+            match self.foreign_balances.get(&key) {
+                // the token has been registered again in the meantime
+                Some(balance) => {
+                    self.foreign_balances.insert(&key, &(balance + amount.0));
+                    self.internal_release_foreign_token(key.1);
+                }
+                None => {
+                    self.foreign_balances.insert(&key, &amount.0);
+                }
+            }
+            0.into()
+        }
+    }
+
+    fn foreign_balances(
+        &self,
+        from_index: Option<U64>,
+        limit: Option<U64>,
+    ) -> Vec<ForeignBalanceView> {
+        let from_index = from_index
+            .map(|from_index| from_index.0)
+            .unwrap_or_default();
+        let limit = limit.map(|limit| limit.0).unwrap_or(u64::MAX);
+        self.foreign_balances
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(|((token_id, sender_id), amount)| ForeignBalanceView {
+                token_id,
+                sender_id,
+                amount: amount.into(),
+            })
+            .collect()
+    }
+}
+
+impl Contract {
+    /// Keeps tokens of `token_id` that have been sent by `sender_id` via `ft_transfer_call`,
+    /// so that they can be withdrawn again. Panics if `sender_id` has not registered the token,
+    /// which makes `token_id` refund the tokens. This also rejects direct calls of
+    /// `ft_on_transfer`, as the predecessor is taken as `token_id`.
+    pub(crate) fn internal_receive_foreign_token(
+        &mut self,
+        token_id: AccountId,
+        sender_id: AccountId,
+        amount: Balance,
+    ) {
+        let balance = self
+            .foreign_balances
+            .get(&(token_id.clone(), sender_id.clone()))
+            .unwrap_or_else(|| env::panic_str("Token is not registered by the sender"));
+        ForeignTokenReceived {
+            token_id: &token_id,
+            sender_id: &sender_id,
+            amount: amount.into(),
+        }
+        .emit();
+        self.foreign_balances
+            .insert(&(token_id, sender_id), &(balance + amount));
+    }
+
+    /// Releases a registration of `sender_id`, whose entry has been removed,
+    /// and refunds its deposit.
+    fn internal_release_foreign_token(&mut self, sender_id: AccountId) -> Promise {
+        let count = self
+            .foreign_token_counts
+            .get(&sender_id)
+            .unwrap_or_default();
+        if count > 1 {
+            self.foreign_token_counts.insert(&sender_id, &(count - 1));
+        } else {
+            self.foreign_token_counts.remove(&sender_id);
+        }
+        Promise::new(sender_id).transfer(self.foreign_token_storage_cost())
+    }
+}
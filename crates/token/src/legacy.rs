@@ -0,0 +1,115 @@
+use crate::{event::LegacyTokenUpdate, pause::PauseCategory, Contract, ContractExt};
+use near_contract_standards::fungible_token::{receiver::FungibleTokenReceiver, Balance};
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::U128,
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId, PromiseOrValue,
+};
+
+/// SHITZUv1 NEP-141 token on NEAR that can be swapped one-to-one via `ft_transfer_call`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct LegacyToken {
+    pub enabled: bool,
+    /// Amount of legacy tokens that have been swapped and are locked in this contract.
+    pub locked: Balance,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LegacyTokenView {
+    pub token_id: AccountId,
+    pub enabled: bool,
+    pub locked: U128,
+}
+
+pub trait LegacyTokenManagement {
+    /// Allows or disallows swapping tokens of `token_id`.
+    /// Can only be called by the owner.
+    fn set_legacy_token(&mut self, token_id: AccountId, enabled: bool);
+
+    fn legacy_tokens(&self) -> Vec<LegacyTokenView>;
+}
+
+#[near_bindgen]
+impl LegacyTokenManagement for Contract {
+    fn set_legacy_token(&mut self, token_id: AccountId, enabled: bool) {
+        self.assert_owner();
+        require!(
+            token_id != env::current_account_id(),
+            "Token can not be swapped into itself"
+        );
+        let mut legacy_token = self
+            .legacy_tokens
+            .get(&token_id)
+            .unwrap_or(LegacyToken { enabled, locked: 0 });
+        legacy_token.enabled = enabled;
+        self.legacy_tokens.insert(&token_id, &legacy_token);
+
+        LegacyTokenUpdate {
+            token_id: &token_id,
+            enabled,
+        }
+        .emit();
+    }
+
+    fn legacy_tokens(&self) -> Vec<LegacyTokenView> {
+        self.legacy_tokens
+            .iter()
+            .map(|(token_id, legacy_token)| LegacyTokenView {
+                token_id,
+                enabled: legacy_token.enabled,
+                locked: legacy_token.locked.into(),
+            })
+            .collect()
+    }
+}
+
+#[near_bindgen]
+impl FungibleTokenReceiver for Contract {
+    /// Swaps legacy tokens one-to-one. They are minted to the account given in `msg`
+    /// or to `sender_id` if `msg` is empty, while the legacy tokens stay locked in this contract.
+    /// If the swap fails, the legacy token refunds the transferred tokens to `sender_id`.
+    /// Tokens of other contracts are kept for `sender_id` until they are withdrawn
+    /// if `sender_id` has registered them via `register_foreign_token`,
+    /// otherwise they are refunded.
+    fn ft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        amount: U128,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
+        let token_id = env::predecessor_account_id();
+        require!(
+            token_id != env::current_account_id(),
+            "Token can not be swapped"
+        );
+        let Some(mut legacy_token) = self.legacy_tokens.get(&token_id) else {
+            self.internal_receive_foreign_token(token_id, sender_id, amount.0);
+            return PromiseOrValue::Value(0.into());
+        };
+        require!(legacy_token.enabled, "Token can not be swapped");
+        self.assert_not_paused(PauseCategory::Mint);
+        self.assert_migration_open();
+        let account_id = if msg.is_empty() {
+            sender_id
+        } else {
+            msg.parse()
+                .unwrap_or_else(|_| env::panic_str("Invalid account ID"))
+        };
+        self.internal_assert_can_deposit_migration(&account_id, amount.0);
+        if !self.internal_consume_mint_rate_limit(amount.0) {
+            return PromiseOrValue::Promise(
+                Self::ext(env::current_account_id()).on_mint_rate_limit_exceeded(),
+            );
+        }
+
+        legacy_token.locked += amount.0;
+        self.legacy_tokens.insert(&token_id, &legacy_token);
+        self.internal_deposit_migration(&account_id, amount.0, None, None);
+        PromiseOrValue::Value(0.into())
+    }
+}
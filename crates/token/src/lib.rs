@@ -1,28 +1,122 @@
+mod airdrop;
+mod bonus;
+mod claim;
 mod core;
+mod event;
+mod evm;
+mod foreign;
+mod legacy;
+mod lock;
+mod metadata;
+mod migration;
+mod minter;
+mod owner;
+mod pause;
+mod rate_limit;
+mod recovery;
+mod role;
 mod storage;
 
+use airdrop::AirdropCampaign;
+use bonus::BonusPool;
+use claim::ClaimAccount;
+use event::ShitzuMigrate;
+use legacy::LegacyToken;
+use lock::{Lock, MintLock};
+use migration::{MigrationStats, MigrationWindow};
+use minter::MinterInfo;
 use near_contract_standards::fungible_token::{
     events::{FtBurn, FtMint},
-    metadata::{FungibleTokenMetadata, FungibleTokenMetadataProvider},
-    FungibleToken, FungibleTokenResolver,
+    metadata::FungibleTokenMetadata,
+    receiver::ext_ft_receiver,
+    Balance, FungibleToken, FungibleTokenResolver,
 };
 use near_sdk::{
     borsh::{BorshDeserialize, BorshSerialize},
+    collections::{LazyOption, LookupMap, LookupSet, UnorderedMap, Vector},
     env,
-    json_types::U128,
-    near_bindgen, require, AccountId, BorshStorageKey, PanicOnDefault, Promise,
+    json_types::{U128, U64},
+    near_bindgen, require, AccountId, BorshStorageKey, CryptoHash, Gas, PanicOnDefault, Promise,
+    PromiseOrValue,
 };
+use owner::PendingOwner;
+use pause::{PauseCategory, PauseStatus};
+use rate_limit::MintRateLimit;
+use recovery::{RecoveryConfig, RecoveryRequest};
+use role::Role;
+use storage::StorageSponsorship;
+
+const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas::from_tgas(5);
+const GAS_FOR_MINT_CALL: Gas = Gas::from_tgas(40);
 
 #[derive(BorshStorageKey, BorshSerialize)]
 #[borsh(crate = "near_sdk::borsh")]
 pub enum StorageKey {
     Token,
+    Metadata,
+    Roles,
+    Migrated,
+    ProcessedMigrations,
+    Minters,
+    StoragePayers,
+    LegacyTokens,
+    ClaimAccounts,
+    AirdropCampaigns,
+    AirdropClaims,
+    Locks,
+    RecoveryRequests,
+    ForeignBalances,
+    ForeignTokenCounts,
 }
 
 #[near_bindgen]
 #[derive(BorshSerialize, BorshDeserialize, PanicOnDefault)]
 #[borsh(crate = "near_sdk::borsh")]
 pub struct Contract {
+    owner: AccountId,
+    pending_owner: Option<PendingOwner>,
+    migrate_address: AccountId,
+    token: FungibleToken,
+    metadata: LazyOption<FungibleTokenMetadata>,
+    roles: LookupMap<Role, Vec<AccountId>>,
+    minters: UnorderedMap<AccountId, MinterInfo>,
+    pause_status: PauseStatus,
+    mint_rate_limit: MintRateLimit,
+    migration_window: MigrationWindow,
+    migration_stats: MigrationStats,
+    migrated: LookupMap<AccountId, u128>,
+    processed_migrations: LookupMap<(AccountId, u64), u128>,
+    storage_payers: LookupMap<AccountId, AccountId>,
+    storage_sponsorship: StorageSponsorship,
+    legacy_tokens: UnorderedMap<AccountId, LegacyToken>,
+    claim_accounts: LookupMap<[u8; 20], ClaimAccount>,
+    claim_snapshot_published: bool,
+    airdrop_campaigns: Vector<AirdropCampaign>,
+    airdrop_claims: LookupSet<(u64, CryptoHash)>,
+    bonus_pool: BonusPool,
+    mint_lock: Option<MintLock>,
+    locks: LookupMap<AccountId, Vec<Lock>>,
+    recovery_requests: UnorderedMap<u64, RecoveryRequest>,
+    recovery_config: RecoveryConfig,
+    foreign_balances: UnorderedMap<(AccountId, AccountId), Balance>,
+    foreign_token_counts: LookupMap<AccountId, u32>,
+}
+
+/// Arguments of `mint_borsh`.
+#[derive(BorshSerialize, BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MintArgs {
+    /// Validated after deserialization, so that invalid account IDs can be rejected explicitly.
+    pub account_id: String,
+    pub amount: Balance,
+    pub migration_id: u64,
+    pub aurora_sender: [u8; 20],
+}
+
+/// State layout of the currently deployed contract.
+#[derive(BorshDeserialize)]
+#[borsh(crate = "near_sdk::borsh")]
+struct ContractV1 {
     owner: AccountId,
     migrate_address: AccountId,
     token: FungibleToken,
@@ -32,64 +126,139 @@ pub struct Contract {
 impl Contract {
     #[init]
     pub fn new(owner: AccountId, migrate_address: AccountId) -> Self {
-        Self {
+        Self::internal_new(
             owner,
             migrate_address,
-            token: FungibleToken::new(StorageKey::Token),
-        }
+            FungibleToken::new(StorageKey::Token),
+        )
     }
 
-    pub fn mint(&mut self, account_id: AccountId, amount: U128) {
-        require!(
-            env::block_timestamp() < 1_718_409_600_000_000_000,
-            "Migration closed indefinitely"
-        );
+    /// Mints tokens for a migration from SHITZUv1.
+    /// If a `migration_id` is given, it has to be unique, so that retried migrations
+    /// will not mint twice.
+    /// `aurora_sender` is the 0x-prefixed EVM address that initiated the migration on Aurora.
+    /// `account_id` can be a named or an ETH-implicit account.
+    /// If the mint would exceed the mint rate limit, minting gets paused and the mint fails.
+    /// `amount` is converted with the exchange rate of the minter, the minted amount is returned.
+    /// Early migrations receive a bonus from the bonus pool on top of the minted amount.
+    pub fn mint(
+        &mut self,
+        account_id: AccountId,
+        amount: U128,
+        migration_id: Option<U64>,
+        aurora_sender: Option<String>,
+    ) -> PromiseOrValue<U128> {
+        let aurora_sender = aurora_sender.map(|aurora_sender| evm::parse_address(&aurora_sender));
+        self.internal_mint(
+            &account_id,
+            amount.0,
+            migration_id.map(|id| id.0),
+            aurora_sender,
+        )
+    }
+
+    /// Same as `mint`, but transfers the minted tokens to `receiver_id` right away and calls
+    /// `ft_on_transfer` on it with `msg`, like `ft_transfer_call` by `account_id` would.
+    /// Tokens that are not used by the receiver end up with `account_id`.
+    /// The share of the minted tokens that gets locked stays with `account_id`.
+    /// Fails while transfers are paused.
+    pub fn mint_call(
+        &mut self,
+        account_id: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+        msg: String,
+        migration_id: Option<U64>,
+        aurora_sender: Option<String>,
+    ) -> PromiseOrValue<U128> {
         require!(
-            env::predecessor_account_id() == self.migrate_address,
-            "Only Shitzu address on Aurora can call this function"
+            env::prepaid_gas() > GAS_FOR_MINT_CALL,
+            "More gas is required"
         );
-        if !self.token.accounts.contains_key(&account_id) {
-            self.token.internal_register_account(&account_id);
+        self.assert_not_paused(PauseCategory::Transfers);
+        let aurora_sender = aurora_sender.map(|aurora_sender| evm::parse_address(&aurora_sender));
+        let minted = match self.internal_mint_migration(
+            &account_id,
+            amount.0,
+            migration_id.map(|id| id.0),
+            aurora_sender,
+        ) {
+            Ok(minted) => minted,
+            Err(promise) => return PromiseOrValue::Promise(promise),
+        };
+        let amount = minted.amount - minted.locked;
+        if amount == 0 {
+            return PromiseOrValue::Value(0.into());
         }
-        self.token.internal_deposit(&account_id, amount.into());
-        FtMint {
-            owner_id: &account_id,
-            amount,
-            memo: None,
-        }
-        .emit();
+        self.internal_assert_unlocked(&account_id, amount);
+        self.token
+            .internal_transfer(&account_id, &receiver_id, amount, None);
+        let amount = U128(amount);
+
+        let receiver_gas = env::prepaid_gas().saturating_sub(GAS_FOR_MINT_CALL);
+        ext_ft_receiver::ext(receiver_id.clone())
+            .with_static_gas(receiver_gas)
+            .ft_on_transfer(account_id.clone(), amount, msg)
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_RESOLVE_TRANSFER)
+                    .ft_resolve_transfer(account_id, receiver_id, amount),
+            )
+            .into()
+    }
+
+    /// Same as `mint`, but takes Borsh encoded arguments, so that the migration contract
+    /// on Aurora can pass the account ID without having to escape it.
+    pub fn mint_borsh(&mut self, #[serializer(borsh)] args: MintArgs) -> PromiseOrValue<U128> {
+        let account_id: AccountId = args
+            .account_id
+            .parse()
+            .unwrap_or_else(|_| env::panic_str("Invalid account ID"));
+        self.internal_mint(
+            &account_id,
+            args.amount,
+            Some(args.migration_id),
+            Some(args.aurora_sender),
+        )
+    }
+
+    #[private]
+    pub fn on_mint_rate_limit_exceeded(&self) {
+        env::panic_str("Mint rate limit exceeded");
     }
 
     /// This function recovers tokens that have been accidentially sent to the contract address itself
     /// and will send those tokens back to the contract owner's address.
     pub fn recover(&mut self) {
+        self.assert_role(Role::Recoverer);
         let self_id = env::current_account_id();
-        let balance = self.token.internal_unwrap_balance_of(&self_id);
+        // tokens of airdrop campaigns and the bonus pool are not recoverable
+        let balance = self.token.internal_unwrap_balance_of(&self_id)
+            - self.internal_airdrop_escrow()
+            - self.bonus_pool.balance;
         require!(balance > 0, "Balance is zero");
         self.token.internal_withdraw(&self_id, balance);
         self.token.internal_deposit(&self.owner, balance);
     }
 
-    /// Since within4d45 has sent his burner account balance to account '114155'
-    /// instead sending 114155 tokens, he created this recovery function
-    /// See tx: 2zmB5uumyaUf4hzCeDyaqH81Fpk9b2iRoAZq2Na3bP3C
-    pub fn recover_within(&mut self) {
-        let id: AccountId = "114155".parse().unwrap();
-        let balance = self.token.internal_unwrap_balance_of(&id);
-        require!(balance > 0, "Balance is zero");
-        self.token.internal_withdraw(&id, balance);
-        self.token.internal_deposit(&self.owner, balance);
-    }
-
-    pub fn migrate(&mut self) {
-        // empty for now
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let ContractV1 {
+            owner,
+            migrate_address,
+            token,
+        } = env::state_read().expect("Contract state is missing");
+        let mut this = Self::internal_new(owner, migrate_address, token);
+        // keep the migration closed as it has been hardcoded before
+        this.migration_window.closes_at = Some(1_718_409_600_000_000_000);
+        // all tokens in circulation have been minted through the migration
+        this.migration_stats.total_migrated = this.token.total_supply;
+        this
     }
 
     pub fn upgrade(&self) -> Promise {
-        require!(
-            env::predecessor_account_id() == self.owner,
-            "Only account owner can update the code"
-        );
+        self.assert_role(Role::Upgrader);
 
         let code = env::input().expect("Error: No input").to_vec();
 
@@ -100,6 +269,157 @@ impl Contract {
     }
 }
 
+/// Tokens that have been minted by a migration and the part of them that has been locked.
+struct MintedMigration {
+    amount: Balance,
+    locked: Balance,
+}
+
+impl Contract {
+    fn internal_mint(
+        &mut self,
+        account_id: &AccountId,
+        amount: Balance,
+        migration_id: Option<u64>,
+        aurora_sender: Option<[u8; 20]>,
+    ) -> PromiseOrValue<U128> {
+        match self.internal_mint_migration(account_id, amount, migration_id, aurora_sender) {
+            Ok(minted) => PromiseOrValue::Value(minted.amount.into()),
+            Err(promise) => PromiseOrValue::Promise(promise),
+        }
+    }
+
+    /// Mints a migration or returns a failing promise if it exceeds the mint rate limit.
+    fn internal_mint_migration(
+        &mut self,
+        account_id: &AccountId,
+        amount: Balance,
+        migration_id: Option<u64>,
+        aurora_sender: Option<[u8; 20]>,
+    ) -> Result<MintedMigration, Promise> {
+        self.assert_not_paused(PauseCategory::Mint);
+        self.assert_migration_open();
+        self.assert_role(Role::Minter);
+        let minter_id = env::predecessor_account_id();
+        self.internal_assert_bridge_open(&minter_id);
+        // the claim snapshot holds SHITZUv1 amounts, which are charged before the conversion
+        let legacy_amount = amount;
+        let amount = self.internal_convert_minter_amount(&minter_id, amount);
+        // invalid mints have to fail before they can trip the rate limit
+        self.internal_assert_minter_can_mint(&minter_id, amount);
+        if let Some(migration_id) = migration_id {
+            self.internal_assert_migration_not_processed(&minter_id, migration_id);
+        }
+        self.internal_assert_can_deposit_migration(account_id, amount);
+        let claim_account = aurora_sender
+            .and_then(|address| self.internal_charged_claim_account(&address, legacy_amount));
+        if !self.internal_consume_mint_rate_limit(amount) {
+            // panicking would revert the pause, so the mint fails through a failing callback
+            return Err(Self::ext(env::current_account_id()).on_mint_rate_limit_exceeded());
+        }
+        self.internal_charge_minter(&minter_id, amount);
+        if let (Some(address), Some(claim_account)) = (aurora_sender, claim_account) {
+            self.claim_accounts.insert(&address, &claim_account);
+        }
+        if let Some(migration_id) = migration_id {
+            self.internal_mark_migration_processed(&minter_id, migration_id);
+        }
+        let locked =
+            self.internal_deposit_migration(account_id, amount, migration_id, aurora_sender);
+        Ok(MintedMigration { amount, locked })
+    }
+
+    /// Panics if depositing `amount` migrated tokens to `account_id` would fail.
+    /// Used to validate migrations before they count against the mint rate limit.
+    pub(crate) fn internal_assert_can_deposit_migration(
+        &self,
+        account_id: &AccountId,
+        amount: Balance,
+    ) {
+        evm::assert_valid_recipient(account_id);
+        self.internal_total_migrated_after(amount);
+        if !self.token.accounts.contains_key(account_id) {
+            self.internal_assert_sponsorship_covers(account_id);
+        }
+    }
+
+    /// Deposits migrated tokens to `account_id` and updates the migration accounting.
+    /// The migration bonus is paid on top of it and the configured share gets locked.
+    /// Returns the locked amount.
+    pub(crate) fn internal_deposit_migration(
+        &mut self,
+        account_id: &AccountId,
+        amount: Balance,
+        migration_id: Option<u64>,
+        aurora_sender: Option<[u8; 20]>,
+    ) -> Balance {
+        evm::assert_valid_recipient(account_id);
+        if !self.token.accounts.contains_key(account_id) {
+            self.internal_register_sponsored_account(account_id);
+        }
+        self.internal_record_migration(account_id, amount);
+        self.token.internal_deposit(account_id, amount);
+        FtMint {
+            owner_id: account_id,
+            amount: amount.into(),
+            memo: None,
+        }
+        .emit();
+        let locked = self.internal_lock_migration(account_id, amount);
+        let bonus = self.internal_pay_migration_bonus(account_id, amount);
+        // normalize the address, so that indexers can rely on its format
+        let aurora_sender = aurora_sender.map(|aurora_sender| evm::encode_address(&aurora_sender));
+        ShitzuMigrate {
+            account_id,
+            amount: amount.into(),
+            aurora_sender: aurora_sender.as_deref(),
+            migration_id: migration_id.map(Into::into),
+            bonus: (bonus > 0).then(|| bonus.into()),
+            locked: (locked > 0).then(|| locked.into()),
+        }
+        .emit();
+        locked
+    }
+
+    fn internal_new(owner: AccountId, migrate_address: AccountId, token: FungibleToken) -> Self {
+        let mut this = Self {
+            owner,
+            pending_owner: None,
+            migrate_address: migrate_address.clone(),
+            token,
+            metadata: LazyOption::new(StorageKey::Metadata, Some(&metadata::default_metadata())),
+            roles: LookupMap::new(StorageKey::Roles),
+            minters: UnorderedMap::new(StorageKey::Minters),
+            pause_status: PauseStatus::default(),
+            mint_rate_limit: MintRateLimit::default(),
+            migration_window: MigrationWindow {
+                opens_at: 0,
+                closes_at: None,
+                finalized: false,
+            },
+            migration_stats: MigrationStats::default(),
+            migrated: LookupMap::new(StorageKey::Migrated),
+            processed_migrations: LookupMap::new(StorageKey::ProcessedMigrations),
+            storage_payers: LookupMap::new(StorageKey::StoragePayers),
+            storage_sponsorship: StorageSponsorship::default(),
+            legacy_tokens: UnorderedMap::new(StorageKey::LegacyTokens),
+            claim_accounts: LookupMap::new(StorageKey::ClaimAccounts),
+            claim_snapshot_published: false,
+            airdrop_campaigns: Vector::new(StorageKey::AirdropCampaigns),
+            airdrop_claims: LookupSet::new(StorageKey::AirdropClaims),
+            bonus_pool: BonusPool::default(),
+            mint_lock: None,
+            locks: LookupMap::new(StorageKey::Locks),
+            recovery_requests: UnorderedMap::new(StorageKey::RecoveryRequests),
+            recovery_config: RecoveryConfig::default(),
+            foreign_balances: UnorderedMap::new(StorageKey::ForeignBalances),
+            foreign_token_counts: LookupMap::new(StorageKey::ForeignTokenCounts),
+        };
+        this.internal_grant_role(Role::Minter, &migrate_address);
+        this
+    }
+}
+
 #[near_bindgen]
 impl FungibleTokenResolver for Contract {
     #[private]
@@ -123,20 +443,3 @@ impl FungibleTokenResolver for Contract {
         used_amount.into()
     }
 }
-
-#[near_bindgen]
-impl FungibleTokenMetadataProvider for Contract {
-    fn ft_metadata(&self) -> FungibleTokenMetadata {
-        FungibleTokenMetadata {
-            spec: "ft-1.0.0".to_string(),
-            name: "SHITZU".to_string(),
-            symbol: "SHITZU".to_string(),
-            icon: Some(ICON.to_string()),
-            reference: None,
-            reference_hash: None,
-            decimals: 18,
-        }
-    }
-}
-
-const ICON: &str = "data:image/webp;base64,UklGRpwIAABXRUJQVlA4TI8IAAAv/8A/EBbfkSRZkmzb1phzojtxeoIxIZgITICqIiPc1NTM/wblb3yr/3goUZCito3YoArm9gR0GI5Iw2R7Qsog0WgKkFUgkOANOmiwYNuJ2zwktCCTNiu2MeDf759ypf+QW+u/2UfSWmuOz/f/doJpXo9yvS1k04mUXcViN11zKcsqWmsqnrlWuz7LWBxT8MzzA7g+NEZHm/HMaa1WtRmH3wdt2spaLzb93eX1WXo9AQUV/48L/6S/AcPJtttWMBHX24aqa1dZNzYWFiqA0qZ2VpZQFG6NdvEgqLK8ovac60Z4qB13Y159ePgdq7g3Gs+lqKONItqmTHaAuvF686aA0olXB41Z6cN5hyJqKqtcWESroDQQtYa1OknVo+6YVtq5ynMDyqpbh3fFNFhFUdYHt+C8S7J33VSQViSsqrMarVGtHWqdLclOb4PcqxLzaf//+03oIo+odZjP+xzSZ1FqFCczeW0dcvuSLxPQMaLW4ESPTt9cvuARxV3Qugc41XP1+brirHAPUAds65jC7y35gwNKAzTCEM/5M+uTP2VQOXBMcQYtaob8how8dXoyk3KoMI89xQctWalLC1ka/QrlV0zaAF1w/VejJeGJAwM6yMvrLEg3mPXQpzDpOoaQMV52Zy1aWNZTI4WrIHOtS2mAt4ZAE5cPxOVjzORaUH4KJfjj3TV8emqhDPFygRY4i2vhuVIBmm4SGa6/mIl3kfuyuNhO+GLwldMlWxET72L20bKDxyJ3AbHKcOSZjZHxdGdL19vCO0jaeNcilWL+aQmSTO9CbeMSbQtrVJC77vosR9/ccoYrUdZsb53CYhF8UnWaVqPIEGxShfYtPAhmsa5kAkt0+xZj/0dKp5RFJR07SegKQjtFcMnYF4b4dq41hBRKidawDO4vEO5cmwWLSMbeLBbhxlYathPMG1IebA2L8+iSBsArINKxpqBpoOs+eUldsrYuBIt07HMi2qNLti44S6BhHM77FjEfXZMSeG80VtIeh0y5M+bRfrkfXTLTBF6CQkTQHEfuW39cws8rcAdbA/sIpQ6vJ1UMtYU6zSjeYxHmRJ49/CMgeWdCViqSKl5IG9BJXiO/AMb/tCiGemJh+5Z6bAwZAfBedLjdroB9gXQ7oc9h/OSJQiA1ccj6F0c+Woo9qw5mpCbQZshkHxtLAelgVeXMmwFDzVEPHogy4ntKtCa3Pfy8Rd5TqgGjcNHoYrAV+jU25ofKrCZ1CW5nTN67bsGLshmwQ0AdwN+sZhU4BKNgVmDsLIyfOg3hqVhQEA4dp2kLVIZHsn2OggWYErIQhSKjGWsBANqbqt3hSKanCjYCl9dUc7Q7A3UBUalgkQC2a312mKYOVBKPo2cNbcXBGghA4n8H6z7qoyd+lEqlcJPgWgROUn50TqmarTC8N4spKfvIe6XAlxPP80wW7gyefqZz0G65tzvLSkOSnPEdLF68gLq4CiRnvSm1ebHI9rqAXQStJvFS4ujsEt6pBeE+5HUj965rPDsNYkOxbPT4i4W2Z4Hn9PhLm6Crykl1uTLYCh4ntTqmAz14eyJQzh6Jm4YHsyR7IplyDO2JwDk7He4boT4mkwPiJJPbxLupAuUkqHZGBTlrr1t7Fpk+uh4estrSZbA9eIbNaKFdDosW+vxD9Cuw8G9ufRyzZZvB8EuNravMyml765jO/w73jEc6/QQSjMXU33mn0NerzXBWLjVchbDFPupY1uZvGDdTxouLLyFT490sJv3DZHY8A6CySds2dxzrAhSiTDhpvysTiSJfQYwoJwjaI/iO14ANWajUI5HhJYCfggqy3zUG7arPIyMBKSBQmyEnXHfBoVZUci+845tTzWqEfAlYAUPro6JCc92dSbKSyYlYfKjJwlx4XqVAjcCXAJldS5BdxFRX9osbdKvraOTAKwWOvTRWa40C92JfAWe2XHP+wFuTRBFTRWsGDbwHNGcliwjVxKaD2t3lRA28WsN00XLQyMtgs1cYNe4sWLRqi2bk9ZdDqBGZMPL+TSGsruT6VhEuyShZRVSUJpLfxSDUl6wiXJFhMke0CMNkGaG+ZJJQf7UKNe9U5qyD/fW5QM26lDnrZHNSxs08mSl6kwZOPFlGqBqzTjx6X1Fjtngnnn3zXB5dWAntFNTIeWfLCGv0IpLi/K5vPiVH/6A43xTTzgNRKAs1CEUx8fydROKCfqwQA30kq25IHXYhCz6m53neZL6/Xw5S7rADSmxPWgkXJg6tQorExVVi4xpUgzB02Q1Mqy5hgvZWGLGGjBPWnVEmxsMlCiM2WlM2ky07JmnUjeGJBxymrDk3zbbwmPeSaCADdlA1DqesvIcpJmXUkQByMhm+7Bo+Shp2UDyZ0QswW8QLL0JOyTn61YHA0L+iDKagZFWReVcEb3fIG85l1501IgeBOpxo5fH9/fISUNE+ICPzvsrkncHMBFPkyLRTjkFXEOTCyloLb/NGKR6Vgk45dhy0JYnJCaPoo+GcDe8sJqd3QxQHgSoBXVQ9tgTXQgRoi2dk8Km1ayy8cYScORcr4ASV87kK4RQNgiXT2MI7yxB4xZE4SHb6ylPr0Lv3BhfpfCDK4QFl8hfeWn3VudDZqj9mY/AhP5nGujH+fr+DVDmNrj2ZnTpRPLr05nlS2ZVHr7cJxcNL754l+R3k2qtOdwPcwrLbp7nw9u8wubD2rno3GhJQZrnw/nWPK+xaNKxa1LhST1sN3XYFpcdv1FvWWSU8dBcuq63bLpGZ4lu79ZQfCjlIPDGtv9uukSk8gTrnduM5Pnhg3hY2ihcllGG0ZhRIqDGxel+U1o+CvPUoe+Bo5El9OK0xZeK3HrbuKlpzt15WR5ty+L36vN9Cak/HjknffOL6cZB6prv59R14dlwtY72NfwTTbr10uqGg2L6/iX5T4L0W3HYy78Kh9P1TjvQFAA==";
@@ -0,0 +1,191 @@
+use crate::{event::MintLockUpdate, Contract, ContractExt};
+use near_contract_standards::fungible_token::Balance;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+const MAX_SHARE_BPS: u16 = 10_000;
+
+/// Schedule by which locked tokens become transferable. Timestamps are in nanoseconds.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum LockSchedule {
+    /// All tokens unlock at `unlocks_at`.
+    Cliff { unlocks_at: U64 },
+    /// Tokens unlock linearly between `start` and `end`.
+    Linear { start: U64, end: U64 },
+}
+
+impl LockSchedule {
+    fn locked(&self, amount: Balance, now: u64) -> Balance {
+        match *self {
+            Self::Cliff { unlocks_at } if now < unlocks_at.0 => amount,
+            Self::Cliff { .. } => 0,
+            Self::Linear { start, .. } if now <= start.0 => amount,
+            Self::Linear { end, .. } if now >= end.0 => 0,
+            Self::Linear { start, end } => {
+                let duration = Balance::from(end.0 - start.0);
+                let elapsed = Balance::from(now - start.0);
+                // can't overflow, since `elapsed` and the remainder are below `duration`
+                let unlocked = amount / duration * elapsed + amount % duration * elapsed / duration;
+                amount - unlocked
+            }
+        }
+    }
+
+    fn ends_at(&self) -> u64 {
+        match *self {
+            Self::Cliff { unlocks_at } => unlocks_at.0,
+            Self::Linear { end, .. } => end.0,
+        }
+    }
+}
+
+/// Share of every migration that gets locked with `schedule`.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MintLock {
+    pub share_bps: u16,
+    pub schedule: LockSchedule,
+}
+
+/// Tokens of an account that are locked with `schedule`.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct Lock {
+    pub amount: Balance,
+    pub schedule: LockSchedule,
+}
+
+pub trait TokenLocks {
+    /// Locks a share of all migrated tokens or removes the lock for future migrations.
+    /// Tokens that have already been locked stay locked.
+    /// Can only be called by the owner.
+    fn set_mint_lock(&mut self, mint_lock: Option<MintLock>);
+
+    fn mint_lock(&self) -> Option<MintLock>;
+
+    /// Returns the part of the balance of `account_id` that can not be transferred yet.
+    fn ft_locked_balance_of(&self, account_id: AccountId) -> U128;
+
+    /// Returns the part of the balance of `account_id` that can be transferred.
+    fn ft_unlocked_balance_of(&self, account_id: AccountId) -> U128;
+}
+
+#[near_bindgen]
+impl TokenLocks for Contract {
+    fn set_mint_lock(&mut self, mint_lock: Option<MintLock>) {
+        self.assert_owner();
+        if let Some(mint_lock) = &mint_lock {
+            require!(
+                mint_lock.share_bps > 0 && mint_lock.share_bps <= MAX_SHARE_BPS,
+                "Share has to be between 0 and 100%"
+            );
+            if let LockSchedule::Linear { start, end } = mint_lock.schedule {
+                require!(start.0 < end.0, "Vesting has to start before it ends");
+            }
+        }
+        self.mint_lock = mint_lock;
+
+        MintLockUpdate {
+            mint_lock: self.mint_lock.as_ref(),
+        }
+        .emit();
+    }
+
+    fn mint_lock(&self) -> Option<MintLock> {
+        self.mint_lock.clone()
+    }
+
+    fn ft_locked_balance_of(&self, account_id: AccountId) -> U128 {
+        self.internal_locked_balance_of(&account_id).into()
+    }
+
+    fn ft_unlocked_balance_of(&self, account_id: AccountId) -> U128 {
+        let balance = self.token.accounts.get(&account_id).unwrap_or_default();
+        (balance - self.internal_locked_balance_of(&account_id)).into()
+    }
+}
+
+impl Contract {
+    /// Locks the configured share of `amount`, which has just been deposited to `account_id`,
+    /// and returns the locked amount.
+    pub(crate) fn internal_lock_migration(
+        &mut self,
+        account_id: &AccountId,
+        amount: Balance,
+    ) -> Balance {
+        let Some(mint_lock) = &self.mint_lock else {
+            return 0;
+        };
+        let share_bps = Balance::from(mint_lock.share_bps);
+        let max_bps = Balance::from(MAX_SHARE_BPS);
+        let locked = amount / max_bps * share_bps + amount % max_bps * share_bps / max_bps;
+        if locked == 0 || mint_lock.schedule.ends_at() <= env::block_timestamp() {
+            return 0;
+        }
+
+        let schedule = mint_lock.schedule;
+        let mut locks = self.internal_active_locks(account_id);
+        if let Some(lock) = locks.iter_mut().find(|lock| lock.schedule == schedule) {
+            lock.amount += locked;
+        } else {
+            locks.push(Lock {
+                amount: locked,
+                schedule,
+            });
+        }
+        self.locks.insert(account_id, &locks);
+        locked
+    }
+
+    /// Panics if `amount` exceeds the unlocked balance of `account_id`.
+    /// Removes locks that have fully been unlocked.
+    pub(crate) fn internal_assert_unlocked(&mut self, account_id: &AccountId, amount: Balance) {
+        let Some(locks) = self.locks.get(account_id) else {
+            return;
+        };
+        let active_locks = self.internal_active_locks(account_id);
+        if active_locks.is_empty() {
+            self.locks.remove(account_id);
+            return;
+        } else if active_locks.len() < locks.len() {
+            self.locks.insert(account_id, &active_locks);
+        }
+        let balance = self.token.accounts.get(account_id).unwrap_or_default();
+        require!(
+            amount <= balance - self.internal_locked_balance_of(account_id),
+            "Amount exceeds the unlocked balance"
+        );
+    }
+
+    fn internal_locked_balance_of(&self, account_id: &AccountId) -> Balance {
+        let now = env::block_timestamp();
+        let locked: Balance = self
+            .locks
+            .get(account_id)
+            .unwrap_or_default()
+            .iter()
+            .map(|lock| lock.schedule.locked(lock.amount, now))
+            .sum();
+        // locked tokens can still be burned, e.g. via `storage_unregister`
+        locked.min(self.token.accounts.get(account_id).unwrap_or_default())
+    }
+
+    fn internal_active_locks(&self, account_id: &AccountId) -> Vec<Lock> {
+        let now = env::block_timestamp();
+        let mut locks = self.locks.get(account_id).unwrap_or_default();
+        locks.retain(|lock| lock.schedule.ends_at() > now);
+        locks
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{
+    event::{
+        MaxMigratableSupplyUpdate, MigrateAddressUpdate, MigrationFinalized, MigrationReopened,
+        MigrationWindowUpdate,
+    },
+    evm,
+    role::Role,
+    Contract, ContractExt,
+};
+use near_contract_standards::fungible_token::{events::FtMint, Balance};
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// Time frame in which tokens can be migrated from SHITZUv1.
+/// Timestamps are in nanoseconds. Without `closes_at` the migration stays open indefinitely.
+/// Once finalized, the migration can never be opened again.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MigrationWindow {
+    pub opens_at: u64,
+    pub closes_at: Option<u64>,
+    pub finalized: bool,
+}
+
+impl MigrationWindow {
+    pub fn is_open(&self) -> bool {
+        let now = env::block_timestamp();
+        !self.finalized && self.opens_at <= now && !self.is_closed()
+    }
+
+    /// Whether the window has been open and is now closed.
+    pub fn is_closed(&self) -> bool {
+        self.closes_at
+            .map(|closes_at| closes_at <= env::block_timestamp())
+            .unwrap_or_default()
+    }
+}
+
+/// Accounting of all tokens that have been minted through the migration.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Default, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MigrationStats {
+    pub total_migrated: Balance,
+    pub migration_count: u64,
+    /// Upper limit for `total_migrated`. Without it, migrations are unlimited.
+    pub max_migratable_supply: Option<Balance>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrationStatsView {
+    pub total_migrated: U128,
+    pub migration_count: U64,
+    pub max_migratable_supply: Option<U128>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrationStatus {
+    pub is_open: bool,
+    pub opens_at: U64,
+    pub closes_at: Option<U64>,
+    /// Whether minting has permanently been disabled, which fixes the total supply.
+    pub is_finalized: bool,
+}
+
+pub trait MigrationManagement {
+    fn migration_status(&self) -> MigrationStatus;
+
+    /// Updates the migration window. Can only be called by the owner.
+    /// Once the migration has been closed it needs to be reopened via `reopen_migration`.
+    fn set_migration_window(&mut self, opens_at: U64, closes_at: Option<U64>);
+
+    /// Reopens a closed migration immediately until `closes_at`.
+    /// Can only be called by the owner.
+    fn reopen_migration(&mut self, closes_at: Option<U64>);
+
+    /// Permanently disables minting after the migration window has ended.
+    /// Optionally mints `amount` of tokens representing unmigrated SHITZUv1 to `treasury_id`,
+    /// which counts towards the total migrated amount.
+    /// Can only be called by the owner.
+    fn finalize_migration(&mut self, treasury_id: Option<AccountId>, amount: Option<U128>);
+
+    fn migration_stats(&self) -> MigrationStatsView;
+
+    /// Returns the total amount of tokens that have been migrated to `account_id`.
+    fn migrated_balance_of(&self, account_id: AccountId) -> U128;
+
+    /// Limits the total amount of tokens that can ever be migrated.
+    /// Can only be called by the owner.
+    fn set_max_migratable_supply(&mut self, max_migratable_supply: Option<U128>);
+
+    /// Returns whether a migration with `migration_id` has already been minted by `minter_id`.
+    fn is_migration_processed(&self, minter_id: AccountId, migration_id: U64) -> bool;
+
+    /// Returns the account of the migration contract on Aurora, which is allowed to mint.
+    fn migrate_address(&self) -> AccountId;
+
+    /// Replaces the account of the migration contract on Aurora.
+    /// The `Minter` role is moved from the previous to the new account.
+    /// Can only be called by the owner.
+    fn set_migrate_address(&mut self, migrate_address: AccountId);
+
+    /// Same as `set_migrate_address`, but derives the account from the 0x-prefixed
+    /// EVM address of the migration contract deployed on the Aurora engine `engine_id`.
+    fn set_migrate_evm_address(&mut self, evm_address: String, engine_id: AccountId);
+}
+
+#[near_bindgen]
+impl MigrationManagement for Contract {
+    fn migration_status(&self) -> MigrationStatus {
+        MigrationStatus {
+            is_open: self.migration_window.is_open(),
+            opens_at: self.migration_window.opens_at.into(),
+            closes_at: self.migration_window.closes_at.map(Into::into),
+            is_finalized: self.migration_window.finalized,
+        }
+    }
+
+    fn set_migration_window(&mut self, opens_at: U64, closes_at: Option<U64>) {
+        self.assert_owner();
+        self.assert_migration_not_finalized();
+        require!(
+            !self.migration_window.is_closed(),
+            "Migration is closed, use reopen_migration"
+        );
+        self.internal_set_migration_window(opens_at.0, closes_at.map(|closes_at| closes_at.0));
+
+        MigrationWindowUpdate {
+            opens_at,
+            closes_at,
+        }
+        .emit();
+    }
+
+    fn reopen_migration(&mut self, closes_at: Option<U64>) {
+        self.assert_owner();
+        self.assert_migration_not_finalized();
+        require!(self.migration_window.is_closed(), "Migration is not closed");
+        let opens_at = env::block_timestamp();
+        self.internal_set_migration_window(opens_at, closes_at.map(|closes_at| closes_at.0));
+
+        MigrationReopened {
+            opens_at: opens_at.into(),
+            closes_at,
+            by: &env::predecessor_account_id(),
+        }
+        .emit();
+    }
+
+    fn finalize_migration(&mut self, treasury_id: Option<AccountId>, amount: Option<U128>) {
+        self.assert_owner();
+        self.assert_migration_not_finalized();
+        require!(!self.migration_window.is_open(), "Migration is still open");
+        require!(
+            treasury_id.is_some() == amount.is_some(),
+            "Treasury and amount have to be given together"
+        );
+        self.migration_window.finalized = true;
+
+        if let (Some(treasury_id), Some(amount)) = (&treasury_id, amount) {
+            require!(amount.0 > 0, "Amount must be positive");
+            self.migration_stats.total_migrated = self.internal_total_migrated_after(amount.0);
+            if !self.token.accounts.contains_key(treasury_id) {
+                self.internal_register_sponsored_account(treasury_id);
+            }
+            self.token.internal_deposit(treasury_id, amount.0);
+            FtMint {
+                owner_id: treasury_id,
+                amount,
+                memo: Some("Unmigrated SHITZUv1"),
+            }
+            .emit();
+        }
+
+        MigrationFinalized {
+            treasury_id: treasury_id.as_deref(),
+            amount,
+            by: &env::predecessor_account_id(),
+        }
+        .emit();
+    }
+
+    fn migration_stats(&self) -> MigrationStatsView {
+        MigrationStatsView {
+            total_migrated: self.migration_stats.total_migrated.into(),
+            migration_count: self.migration_stats.migration_count.into(),
+            max_migratable_supply: self.migration_stats.max_migratable_supply.map(Into::into),
+        }
+    }
+
+    fn migrated_balance_of(&self, account_id: AccountId) -> U128 {
+        self.migrated.get(&account_id).unwrap_or_default().into()
+    }
+
+    fn set_max_migratable_supply(&mut self, max_migratable_supply: Option<U128>) {
+        self.assert_owner();
+        if let Some(max_migratable_supply) = max_migratable_supply {
+            require!(
+                max_migratable_supply.0 >= self.migration_stats.total_migrated,
+                "Max migratable supply is lower than the already migrated amount"
+            );
+        }
+        self.migration_stats.max_migratable_supply = max_migratable_supply.map(|max| max.0);
+
+        MaxMigratableSupplyUpdate {
+            max_migratable_supply,
+        }
+        .emit();
+    }
+
+    fn is_migration_processed(&self, minter_id: AccountId, migration_id: U64) -> bool {
+        let (bucket, mask) = processed_migration_slot(migration_id.0);
+        self.processed_migrations
+            .get(&(minter_id, bucket))
+            .unwrap_or_default()
+            & mask
+            != 0
+    }
+
+    fn migrate_address(&self) -> AccountId {
+        self.migrate_address.clone()
+    }
+
+    fn set_migrate_address(&mut self, migrate_address: AccountId) {
+        self.assert_owner();
+        self.internal_set_migrate_address(migrate_address);
+    }
+
+    fn set_migrate_evm_address(&mut self, evm_address: String, engine_id: AccountId) {
+        self.assert_owner();
+        let address = evm::parse_address(&evm_address);
+        self.internal_set_migrate_address(evm::aurora_representative_id(&address, &engine_id));
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_migration_open(&self) {
+        self.assert_migration_not_finalized();
+        require!(self.migration_window.is_open(), "Migration is not open");
+    }
+
+    fn assert_migration_not_finalized(&self) {
+        require!(
+            !self.migration_window.finalized,
+            "Migration has been finalized"
+        );
+    }
+
+    pub(crate) fn internal_record_migration(&mut self, account_id: &AccountId, amount: Balance) {
+        self.migration_stats.total_migrated = self.internal_total_migrated_after(amount);
+        self.migration_stats.migration_count += 1;
+
+        let migrated = self.migrated.get(account_id).unwrap_or_default();
+        self.migrated.insert(account_id, &(migrated + amount));
+    }
+
+    /// Returns the total migrated amount after migrating `amount`.
+    /// Panics if this exceeds the max migratable supply.
+    pub(crate) fn internal_total_migrated_after(&self, amount: Balance) -> Balance {
+        let total_migrated = self
+            .migration_stats
+            .total_migrated
+            .checked_add(amount)
+            .unwrap_or_else(|| env::panic_str("Total migrated overflow"));
+        if let Some(max_migratable_supply) = self.migration_stats.max_migratable_supply {
+            require!(
+                total_migrated <= max_migratable_supply,
+                "Max migratable supply exceeded"
+            );
+        }
+        total_migrated
+    }
+
+    pub(crate) fn internal_assert_migration_not_processed(
+        &self,
+        minter_id: &AccountId,
+        migration_id: u64,
+    ) {
+        require!(
+            !self.is_migration_processed(minter_id.clone(), migration_id.into()),
+            "Migration has already been processed"
+        );
+    }
+
+    /// Processed migration ids are stored as a bitmap with 128 ids per storage entry,
+    /// which keeps storage costs low for sequential ids. Every minter counts its own ids,
+    /// e.g. a redeployed migration contract on Aurora starts again at 0.
+    pub(crate) fn internal_mark_migration_processed(
+        &mut self,
+        minter_id: &AccountId,
+        migration_id: u64,
+    ) {
+        let (bucket, mask) = processed_migration_slot(migration_id);
+        let key = (minter_id.clone(), bucket);
+        let processed = self.processed_migrations.get(&key).unwrap_or_default();
+        require!(
+            processed & mask == 0,
+            "Migration has already been processed"
+        );
+        self.processed_migrations.insert(&key, &(processed | mask));
+    }
+
+    fn internal_set_migrate_address(&mut self, migrate_address: AccountId) {
+        require!(
+            migrate_address != self.migrate_address,
+            "Account is already the migrate address"
+        );
+        let old_migrate_address = self.migrate_address.clone();
+        if self.internal_is_role_member(Role::Minter, &old_migrate_address) {
+            self.internal_remove_role(Role::Minter, &old_migrate_address);
+        }
+        if !self.internal_is_role_member(Role::Minter, &migrate_address) {
+            self.internal_grant_role(Role::Minter, &migrate_address);
+        }
+
+        MigrateAddressUpdate {
+            old_migrate_address: &old_migrate_address,
+            new_migrate_address: &migrate_address,
+        }
+        .emit();
+        self.migrate_address = migrate_address;
+    }
+
+    fn internal_set_migration_window(&mut self, opens_at: u64, closes_at: Option<u64>) {
+        if let Some(closes_at) = closes_at {
+            require!(
+                opens_at < closes_at,
+                "Migration has to open before it closes"
+            );
+            require!(
+                closes_at > env::block_timestamp(),
+                "Migration has to close in the future"
+            );
+        }
+        self.migration_window.opens_at = opens_at;
+        self.migration_window.closes_at = closes_at;
+    }
+}
+
+fn processed_migration_slot(migration_id: u64) -> (u64, u128) {
+    (migration_id / 128, 1 << (migration_id % 128))
+}
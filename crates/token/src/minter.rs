@@ -0,0 +1,272 @@
+use crate::{event::MinterUpdate, metadata::DECIMALS, role::Role, Contract, ContractExt};
+use near_contract_standards::fungible_token::Balance;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// Rate at which a minter converts amounts of a legacy token with `decimals` decimals
+/// into amounts of this token.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ExchangeRate {
+    pub numerator: U128,
+    pub denominator: U128,
+    pub decimals: u8,
+}
+
+impl Default for ExchangeRate {
+    fn default() -> Self {
+        Self {
+            numerator: 1.into(),
+            denominator: 1.into(),
+            decimals: DECIMALS,
+        }
+    }
+}
+
+impl ExchangeRate {
+    /// Returns numerator and denominator with the decimals adjustment applied.
+    fn scaled(&self) -> Option<(Balance, Balance)> {
+        let (numerator, denominator) = (self.numerator.0, self.denominator.0);
+        if self.decimals <= DECIMALS {
+            let factor = 10u128.checked_pow((DECIMALS - self.decimals).into())?;
+            Some((numerator.checked_mul(factor)?, denominator))
+        } else {
+            let factor = 10u128.checked_pow((self.decimals - DECIMALS).into())?;
+            Some((numerator, denominator.checked_mul(factor)?))
+        }
+    }
+
+    /// Converts an amount of the legacy token into an amount of this token.
+    /// The result is rounded down, so that never more than the exact amount is minted.
+    /// Returns `None` if the result does not fit into `u128`.
+    pub fn convert(&self, amount: Balance) -> Option<Balance> {
+        let (numerator, denominator) = self.scaled()?;
+        // amount * numerator / denominator without overflowing for large amounts
+        (amount / denominator)
+            .checked_mul(numerator)?
+            .checked_add((amount % denominator).checked_mul(numerator)? / denominator)
+    }
+}
+
+/// Registry entry of an account with the `Minter` role.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MinterInfo {
+    /// Total amount of tokens this minter can mint. Without it, minting is unlimited.
+    pub quota: Option<Balance>,
+    /// Total amount of tokens this minter has minted so far.
+    pub minted: Balance,
+    /// Timestamp in nanoseconds after which this minter can no longer mint.
+    pub expires_at: Option<u64>,
+    pub enabled: bool,
+    /// Rate at which amounts passed to `mint` are converted.
+    pub rate: ExchangeRate,
+}
+
+impl Default for MinterInfo {
+    fn default() -> Self {
+        Self {
+            quota: None,
+            minted: 0,
+            expires_at: None,
+            enabled: true,
+            rate: ExchangeRate::default(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MinterView {
+    pub account_id: AccountId,
+    pub quota: Option<U128>,
+    pub minted: U128,
+    pub expires_at: Option<U64>,
+    pub enabled: bool,
+    pub rate: ExchangeRate,
+}
+
+pub trait MinterManagement {
+    /// Registers `account_id` as minter, which grants it the `Minter` role.
+    /// Can only be called by the owner.
+    fn add_minter(&mut self, account_id: AccountId, quota: Option<U128>, expires_at: Option<U64>);
+
+    /// Updates quota and expiry of a registered minter.
+    /// Can only be called by the owner.
+    fn update_minter(
+        &mut self,
+        account_id: AccountId,
+        quota: Option<U128>,
+        expires_at: Option<U64>,
+    );
+
+    /// Sets the rate at which amounts minted by `account_id` are converted.
+    /// `decimals` are the decimals of the legacy token, amounts are adjusted to 18 decimals.
+    /// Can only be called by the owner.
+    fn set_minter_rate(&mut self, account_id: AccountId, rate: ExchangeRate);
+
+    /// Enables or disables a registered minter without removing it.
+    /// Can only be called by the owner.
+    fn set_minter_enabled(&mut self, account_id: AccountId, enabled: bool);
+
+    /// Removes a registered minter, which revokes its `Minter` role.
+    /// Can only be called by the owner.
+    fn remove_minter(&mut self, account_id: AccountId);
+
+    fn minter(&self, account_id: AccountId) -> Option<MinterView>;
+
+    fn minters(&self, from_index: Option<U64>, limit: Option<U64>) -> Vec<MinterView>;
+}
+
+#[near_bindgen]
+impl MinterManagement for Contract {
+    fn add_minter(&mut self, account_id: AccountId, quota: Option<U128>, expires_at: Option<U64>) {
+        self.assert_owner();
+        self.internal_grant_role(Role::Minter, &account_id);
+        self.internal_update_minter(&account_id, |minter| {
+            minter.quota = quota.map(|quota| quota.0);
+            minter.expires_at = expires_at.map(|expires_at| expires_at.0);
+        });
+    }
+
+    fn update_minter(
+        &mut self,
+        account_id: AccountId,
+        quota: Option<U128>,
+        expires_at: Option<U64>,
+    ) {
+        self.assert_owner();
+        self.internal_update_minter(&account_id, |minter| {
+            minter.quota = quota.map(|quota| quota.0);
+            minter.expires_at = expires_at.map(|expires_at| expires_at.0);
+        });
+    }
+
+    fn set_minter_rate(&mut self, account_id: AccountId, rate: ExchangeRate) {
+        self.assert_owner();
+        require!(
+            rate.numerator.0 > 0 && rate.denominator.0 > 0,
+            "Rate must be positive"
+        );
+        require!(rate.scaled().is_some(), "Rate overflow");
+        self.internal_update_minter(&account_id, |minter| minter.rate = rate);
+    }
+
+    fn set_minter_enabled(&mut self, account_id: AccountId, enabled: bool) {
+        self.assert_owner();
+        self.internal_update_minter(&account_id, |minter| minter.enabled = enabled);
+    }
+
+    fn remove_minter(&mut self, account_id: AccountId) {
+        self.assert_owner();
+        self.internal_remove_role(Role::Minter, &account_id);
+    }
+
+    fn minter(&self, account_id: AccountId) -> Option<MinterView> {
+        self.minters
+            .get(&account_id)
+            .map(|minter| minter_view(account_id, minter))
+    }
+
+    fn minters(&self, from_index: Option<U64>, limit: Option<U64>) -> Vec<MinterView> {
+        let from_index = from_index
+            .map(|from_index| from_index.0)
+            .unwrap_or_default();
+        let limit = limit.map(|limit| limit.0).unwrap_or(u64::MAX);
+        self.minters
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .map(|(account_id, minter)| minter_view(account_id, minter))
+            .collect()
+    }
+}
+
+impl Contract {
+    /// Converts `amount` with the exchange rate of the minter.
+    pub(crate) fn internal_convert_minter_amount(
+        &self,
+        account_id: &AccountId,
+        amount: Balance,
+    ) -> Balance {
+        let amount = self
+            .minters
+            .get(account_id)
+            .map(|minter| minter.rate.convert(amount))
+            .unwrap_or(Some(amount))
+            .unwrap_or_else(|| env::panic_str("Amount overflow"));
+        require!(amount > 0, "Converted amount is zero");
+        amount
+    }
+
+    /// Checks whether the minter is allowed to mint `amount` and adds it to its minted amount.
+    pub(crate) fn internal_charge_minter(&mut self, account_id: &AccountId, amount: Balance) {
+        let minter = self.internal_charged_minter(account_id, amount);
+        self.minters.insert(account_id, &minter);
+    }
+
+    /// Panics if the minter is not allowed to mint `amount`.
+    pub(crate) fn internal_assert_minter_can_mint(&self, account_id: &AccountId, amount: Balance) {
+        self.internal_charged_minter(account_id, amount);
+    }
+
+    fn internal_charged_minter(&self, account_id: &AccountId, amount: Balance) -> MinterInfo {
+        let mut minter = self.minters.get(account_id).unwrap_or_else(|| {
+            env::panic_str("Only accounts with role Minter can call this function")
+        });
+        require!(minter.enabled, "Minter is disabled");
+        if let Some(expires_at) = minter.expires_at {
+            require!(env::block_timestamp() < expires_at, "Minter has expired");
+        }
+        minter.minted = minter
+            .minted
+            .checked_add(amount)
+            .unwrap_or_else(|| env::panic_str("Minted amount overflow"));
+        if let Some(quota) = minter.quota {
+            require!(minter.minted <= quota, "Minter quota exceeded");
+        }
+        minter
+    }
+
+    fn internal_update_minter(&mut self, account_id: &AccountId, f: impl FnOnce(&mut MinterInfo)) {
+        let mut minter = self
+            .minters
+            .get(account_id)
+            .unwrap_or_else(|| env::panic_str("Minter is not registered"));
+        f(&mut minter);
+        if let Some(quota) = minter.quota {
+            require!(
+                quota >= minter.minted,
+                "Quota is lower than the already minted amount"
+            );
+        }
+        self.minters.insert(account_id, &minter);
+
+        MinterUpdate {
+            account_id,
+            quota: minter.quota.map(Into::into),
+            expires_at: minter.expires_at.map(Into::into),
+            enabled: minter.enabled,
+            rate: &minter.rate,
+        }
+        .emit();
+    }
+}
+
+fn minter_view(account_id: AccountId, minter: MinterInfo) -> MinterView {
+    MinterView {
+        account_id,
+        quota: minter.quota.map(Into::into),
+        minted: minter.minted.into(),
+        expires_at: minter.expires_at.map(Into::into),
+        enabled: minter.enabled,
+        rate: minter.rate,
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    event::{OwnershipTransferCancelled, OwnershipTransferProposed, OwnershipTransferred},
+    Contract, ContractExt,
+};
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::U64,
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// An ownership transfer that still needs to be accepted by the proposed owner.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct PendingOwner {
+    pub account_id: AccountId,
+    /// Timestamp in nanoseconds after which the proposal can no longer be accepted.
+    pub expires_at: Option<U64>,
+}
+
+pub trait Ownable {
+    /// Returns the current contract owner.
+    fn owner(&self) -> AccountId;
+
+    /// Returns the pending ownership transfer, if any.
+    fn pending_owner(&self) -> Option<PendingOwner>;
+
+    /// Proposes `account_id` as the new contract owner.
+    /// The transfer only takes effect after the proposed account calls `accept_ownership`.
+    /// A previous proposal is replaced.
+    fn propose_owner(&mut self, account_id: AccountId, expires_at: Option<U64>);
+
+    /// Accepts a pending ownership transfer. Needs to be called by the proposed owner.
+    fn accept_ownership(&mut self);
+
+    /// Cancels a pending ownership transfer. Needs to be called by the current owner.
+    fn cancel_ownership_transfer(&mut self);
+}
+
+#[near_bindgen]
+impl Ownable for Contract {
+    fn owner(&self) -> AccountId {
+        self.owner.clone()
+    }
+
+    fn pending_owner(&self) -> Option<PendingOwner> {
+        self.pending_owner.clone()
+    }
+
+    fn propose_owner(&mut self, account_id: AccountId, expires_at: Option<U64>) {
+        self.assert_owner();
+        require!(account_id != self.owner, "Account is already the owner");
+        if let Some(expires_at) = expires_at {
+            require!(
+                expires_at.0 > env::block_timestamp(),
+                "Expiry has to be in the future"
+            );
+        }
+
+        OwnershipTransferProposed {
+            owner_id: &self.owner,
+            pending_owner_id: &account_id,
+            expires_at,
+        }
+        .emit();
+        self.pending_owner = Some(PendingOwner {
+            account_id,
+            expires_at,
+        });
+    }
+
+    fn accept_ownership(&mut self) {
+        let pending_owner = self
+            .pending_owner
+            .take()
+            .unwrap_or_else(|| env::panic_str("No pending ownership transfer"));
+        require!(
+            env::predecessor_account_id() == pending_owner.account_id,
+            "Only the pending owner can accept the ownership"
+        );
+        if let Some(expires_at) = pending_owner.expires_at {
+            require!(
+                env::block_timestamp() <= expires_at.0,
+                "Ownership transfer has expired"
+            );
+        }
+
+        OwnershipTransferred {
+            old_owner_id: &self.owner,
+            new_owner_id: &pending_owner.account_id,
+        }
+        .emit();
+        self.owner = pending_owner.account_id;
+    }
+
+    fn cancel_ownership_transfer(&mut self) {
+        self.assert_owner();
+        let pending_owner = self
+            .pending_owner
+            .take()
+            .unwrap_or_else(|| env::panic_str("No pending ownership transfer"));
+
+        OwnershipTransferCancelled {
+            owner_id: &self.owner,
+            pending_owner_id: &pending_owner.account_id,
+        }
+        .emit();
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_owner(&self) {
+        require!(
+            env::predecessor_account_id() == self.owner,
+            "Only account owner can call this function"
+        );
+    }
+}
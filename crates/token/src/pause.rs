@@ -0,0 +1,108 @@
+use crate::{
+    event::{Paused, Unpaused},
+    role::Role,
+    Contract, ContractExt,
+};
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env, near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// Categories of contract functionality that can be paused independently.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum PauseCategory {
+    /// `ft_transfer`, `ft_transfer_call` and airdrop `claim`
+    Transfers,
+    /// `mint`
+    Mint,
+    /// `storage_withdraw` and `storage_unregister`
+    Storage,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Default, Debug)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct PauseStatus {
+    pub transfers: bool,
+    pub mint: bool,
+    pub storage: bool,
+}
+
+impl PauseStatus {
+    fn get_mut(&mut self, category: PauseCategory) -> &mut bool {
+        match category {
+            PauseCategory::Transfers => &mut self.transfers,
+            PauseCategory::Mint => &mut self.mint,
+            PauseCategory::Storage => &mut self.storage,
+        }
+    }
+
+    pub fn is_paused(&self, category: PauseCategory) -> bool {
+        match category {
+            PauseCategory::Transfers => self.transfers,
+            PauseCategory::Mint => self.mint,
+            PauseCategory::Storage => self.storage,
+        }
+    }
+}
+
+pub trait Pausable {
+    /// Pauses the given categories. Can be called by accounts with the `Pauser` role.
+    fn pause(&mut self, categories: Vec<PauseCategory>);
+
+    /// Unpauses the given categories. Can only be called by the owner.
+    fn unpause(&mut self, categories: Vec<PauseCategory>);
+
+    fn pause_status(&self) -> PauseStatus;
+}
+
+#[near_bindgen]
+impl Pausable for Contract {
+    fn pause(&mut self, categories: Vec<PauseCategory>) {
+        self.assert_role(Role::Pauser);
+        require!(!categories.is_empty(), "No categories given");
+        self.internal_pause(&categories, &env::predecessor_account_id());
+    }
+
+    fn unpause(&mut self, categories: Vec<PauseCategory>) {
+        self.assert_owner();
+        require!(!categories.is_empty(), "No categories given");
+        for category in &categories {
+            *self.pause_status.get_mut(*category) = false;
+        }
+
+        Unpaused {
+            categories: &categories,
+            by: &env::predecessor_account_id(),
+        }
+        .emit();
+    }
+
+    fn pause_status(&self) -> PauseStatus {
+        self.pause_status.clone()
+    }
+}
+
+impl Contract {
+    pub(crate) fn internal_pause(&mut self, categories: &[PauseCategory], by: &AccountId) {
+        for category in categories {
+            *self.pause_status.get_mut(*category) = true;
+        }
+
+        Paused { categories, by }.emit();
+    }
+
+    pub(crate) fn assert_not_paused(&self, category: PauseCategory) {
+        require!(
+            !self.pause_status.is_paused(category),
+            format!("{:?} is paused", category)
+        );
+    }
+}
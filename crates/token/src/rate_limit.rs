@@ -0,0 +1,117 @@
+use crate::{
+    event::{MintRateLimitExceeded, MintRateLimitUpdate},
+    pause::PauseCategory,
+    Contract, ContractExt,
+};
+use near_contract_standards::fungible_token::Balance;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+};
+
+const DEFAULT_WINDOW: u64 = 60 * 60 * 1_000_000_000;
+
+/// Limits the amount of tokens that can be minted within a time window across all minters.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct MintRateLimit {
+    /// Length of the window in nanoseconds.
+    pub window: u64,
+    /// Amount of tokens that can be minted per window. Without it, minting is not rate limited.
+    pub limit: Option<Balance>,
+    /// Timestamp in nanoseconds at which the current window has started.
+    pub window_start: u64,
+    /// Amount of tokens minted within the current window.
+    pub window_usage: Balance,
+}
+
+impl Default for MintRateLimit {
+    fn default() -> Self {
+        Self {
+            window: DEFAULT_WINDOW,
+            limit: None,
+            window_start: 0,
+            window_usage: 0,
+        }
+    }
+}
+
+impl MintRateLimit {
+    fn current_window(&self, now: u64) -> (u64, Balance) {
+        if now >= self.window_start.saturating_add(self.window) {
+            (now, 0)
+        } else {
+            (self.window_start, self.window_usage)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MintRateLimitView {
+    pub window: U64,
+    pub limit: Option<U128>,
+    pub window_start: U64,
+    pub window_usage: U128,
+}
+
+pub trait MintRateLimiting {
+    /// Sets the length of the rate limit window in nanoseconds and the amount of tokens
+    /// that can be minted per window. Removes the rate limit if no limit is given.
+    /// Can only be called by the owner.
+    fn set_mint_rate_limit(&mut self, window: U64, limit: Option<U128>);
+
+    fn mint_rate_limit(&self) -> MintRateLimitView;
+}
+
+#[near_bindgen]
+impl MintRateLimiting for Contract {
+    fn set_mint_rate_limit(&mut self, window: U64, limit: Option<U128>) {
+        self.assert_owner();
+        require!(window.0 > 0, "Window must not be empty");
+        self.mint_rate_limit.window = window.0;
+        self.mint_rate_limit.limit = limit.map(|limit| limit.0);
+
+        MintRateLimitUpdate { window, limit }.emit();
+    }
+
+    fn mint_rate_limit(&self) -> MintRateLimitView {
+        let (window_start, window_usage) =
+            self.mint_rate_limit.current_window(env::block_timestamp());
+        MintRateLimitView {
+            window: self.mint_rate_limit.window.into(),
+            limit: self.mint_rate_limit.limit.map(Into::into),
+            window_start: window_start.into(),
+            window_usage: window_usage.into(),
+        }
+    }
+}
+
+impl Contract {
+    /// Adds `amount` to the usage of the current window.
+    /// If this exceeds the limit, minting gets paused until the owner unpauses it
+    /// and `false` is returned.
+    pub(crate) fn internal_consume_mint_rate_limit(&mut self, amount: Balance) -> bool {
+        let (window_start, window_usage) =
+            self.mint_rate_limit.current_window(env::block_timestamp());
+        let new_window_usage = window_usage.saturating_add(amount);
+        if let Some(limit) = self.mint_rate_limit.limit {
+            if new_window_usage > limit {
+                self.internal_pause(&[PauseCategory::Mint], &env::current_account_id());
+                MintRateLimitExceeded {
+                    amount: amount.into(),
+                    window_usage: window_usage.into(),
+                    limit: limit.into(),
+                }
+                .emit();
+                return false;
+            }
+        }
+        self.mint_rate_limit.window_start = window_start;
+        self.mint_rate_limit.window_usage = new_window_usage;
+        true
+    }
+}
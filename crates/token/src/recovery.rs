@@ -0,0 +1,230 @@
+use crate::{
+    event::{RecoveryCancelled, RecoveryExecuted, RecoveryFiled, RecoveryTimelockUpdate},
+    role::Role,
+    Contract, ContractExt,
+};
+use near_contract_standards::fungible_token::Balance;
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::{U128, U64},
+    near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+const DAY: u64 = 24 * 60 * 60 * 1_000_000_000;
+const MIN_RECOVERY_TIMELOCK: u64 = DAY;
+const DEFAULT_RECOVERY_TIMELOCK: u64 = 3 * DAY;
+
+/// Recovery of tokens that have been sent to an account that can not hold keys.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct RecoveryRequest {
+    pub source_id: AccountId,
+    pub amount: Balance,
+    pub destination_id: AccountId,
+    pub reason: String,
+    /// Hash of the transaction that has credited the tokens by mistake.
+    pub tx_reference: String,
+    /// Timestamp in nanoseconds after which the recovery can be executed.
+    pub executable_at: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct RecoveryConfig {
+    /// Time in nanoseconds for which requests are visible before they can be executed.
+    pub timelock: u64,
+    pub next_id: u64,
+}
+
+impl Default for RecoveryConfig {
+    fn default() -> Self {
+        Self {
+            timelock: DEFAULT_RECOVERY_TIMELOCK,
+            next_id: 0,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecoveryRequestView {
+    pub id: U64,
+    pub source_id: AccountId,
+    pub amount: U128,
+    pub destination_id: AccountId,
+    pub reason: String,
+    pub tx_reference: String,
+    pub executable_at: U64,
+}
+
+pub trait Recovery {
+    /// Files a request to move `amount` from `source_id` to `destination_id`, which can be
+    /// executed after the recovery timelock has passed. Only numeric top-level accounts that
+    /// are shorter than 32 characters, like `114155`, are eligible. They can only be created
+    /// by the registrar and have no keys. Can only be called by the owner.
+    fn file_recovery(
+        &mut self,
+        source_id: AccountId,
+        amount: U128,
+        destination_id: AccountId,
+        reason: String,
+        tx_reference: String,
+    ) -> U64;
+
+    /// Executes a recovery request once its timelock has passed.
+    /// Can only be called by recoverers.
+    fn execute_recovery(&mut self, id: U64);
+
+    /// Removes a pending recovery request. Can only be called by the owner.
+    fn cancel_recovery(&mut self, id: U64);
+
+    /// Sets the time in nanoseconds for which new recovery requests are pending.
+    /// Can only be called by the owner.
+    fn set_recovery_timelock(&mut self, timelock: U64);
+
+    fn recovery_timelock(&self) -> U64;
+
+    fn recovery_requests(&self) -> Vec<RecoveryRequestView>;
+}
+
+#[near_bindgen]
+impl Recovery for Contract {
+    fn file_recovery(
+        &mut self,
+        source_id: AccountId,
+        amount: U128,
+        destination_id: AccountId,
+        reason: String,
+        tx_reference: String,
+    ) -> U64 {
+        self.assert_owner();
+        self.assert_recoverable(&source_id);
+        require!(
+            source_id != destination_id,
+            "Source and destination are equal"
+        );
+        require!(amount.0 > 0, "Amount must be positive");
+        require!(
+            amount.0 <= self.token.accounts.get(&source_id).unwrap_or_default(),
+            "Amount exceeds the balance of the source account"
+        );
+        let id = self.recovery_config.next_id;
+        self.recovery_config.next_id += 1;
+        let request = RecoveryRequest {
+            source_id,
+            amount: amount.0,
+            destination_id,
+            reason,
+            tx_reference,
+            executable_at: env::block_timestamp() + self.recovery_config.timelock,
+        };
+        self.recovery_requests.insert(&id, &request);
+
+        RecoveryFiled {
+            id: id.into(),
+            source_id: &request.source_id,
+            amount,
+            destination_id: &request.destination_id,
+            reason: &request.reason,
+            tx_reference: &request.tx_reference,
+            executable_at: request.executable_at.into(),
+        }
+        .emit();
+        id.into()
+    }
+
+    fn execute_recovery(&mut self, id: U64) {
+        self.assert_role(Role::Recoverer);
+        let request = self
+            .recovery_requests
+            .remove(&id.0)
+            .unwrap_or_else(|| env::panic_str("Recovery request does not exist"));
+        require!(
+            request.executable_at <= env::block_timestamp(),
+            "Recovery is still timelocked"
+        );
+        if !self.token.accounts.contains_key(&request.destination_id) {
+            self.internal_register_sponsored_account(&request.destination_id);
+        }
+        self.token.internal_transfer(
+            &request.source_id,
+            &request.destination_id,
+            request.amount,
+            Some(format!("Recovery {}", id.0)),
+        );
+
+        RecoveryExecuted {
+            id,
+            source_id: &request.source_id,
+            amount: request.amount.into(),
+            destination_id: &request.destination_id,
+            by: &env::predecessor_account_id(),
+        }
+        .emit();
+    }
+
+    fn cancel_recovery(&mut self, id: U64) {
+        self.assert_owner();
+        require!(
+            self.recovery_requests.remove(&id.0).is_some(),
+            "Recovery request does not exist"
+        );
+
+        RecoveryCancelled { id }.emit();
+    }
+
+    fn set_recovery_timelock(&mut self, timelock: U64) {
+        self.assert_owner();
+        require!(
+            timelock.0 >= MIN_RECOVERY_TIMELOCK,
+            "Recovery timelock is too short"
+        );
+        self.recovery_config.timelock = timelock.0;
+
+        RecoveryTimelockUpdate { timelock }.emit();
+    }
+
+    fn recovery_timelock(&self) -> U64 {
+        self.recovery_config.timelock.into()
+    }
+
+    fn recovery_requests(&self) -> Vec<RecoveryRequestView> {
+        self.recovery_requests
+            .iter()
+            .map(|(id, request)| RecoveryRequestView {
+                id: id.into(),
+                source_id: request.source_id,
+                amount: request.amount.into(),
+                destination_id: request.destination_id,
+                reason: request.reason,
+                tx_reference: request.tx_reference,
+                executable_at: request.executable_at.into(),
+            })
+            .collect()
+    }
+}
+
+impl Contract {
+    /// Panics if `account_id` might be able to hold keys.
+    fn assert_recoverable(&self, account_id: &AccountId) {
+        // top-level accounts of 32 or more characters can be created by anyone and short
+        // names like `near` or `aurora` are held by their owners
+        let id = account_id.as_str();
+        require!(
+            id.len() < 32 && id.bytes().all(|byte| byte.is_ascii_digit()),
+            "Account is not eligible for recovery"
+        );
+        require!(
+            account_id != &env::current_account_id(),
+            "Account is not eligible for recovery"
+        );
+        // accounts that have paid for their own storage have signed a transaction before
+        require!(
+            self.storage_payers.get(account_id).as_ref() != Some(account_id),
+            "Account is not eligible for recovery"
+        );
+    }
+}
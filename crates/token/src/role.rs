@@ -0,0 +1,159 @@
+use crate::{
+    event::{RoleGranted, RoleRevoked},
+    minter::MinterInfo,
+    Contract, ContractExt,
+};
+use near_sdk::{
+    borsh::{BorshDeserialize, BorshSerialize},
+    env, near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId,
+};
+
+/// Roles that can be handed out to accounts in order to delegate privileged actions.
+/// The contract owner implicitly holds all roles except for `Minter`,
+/// which always has to be granted explicitly.
+/// Members of the `Minter` role are stored in the minter registry.
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum Role {
+    /// Can grant and revoke all roles except for `Admin` and `Minter` and update token metadata.
+    Admin,
+    /// Can upgrade the contract code.
+    Upgrader,
+    /// Can mint new tokens.
+    Minter,
+    /// Can pause the contract.
+    Pauser,
+    /// Can recover tokens from accounts that can not hold them.
+    Recoverer,
+}
+
+pub trait RoleManagement {
+    /// Grants `role` to `account_id`.
+    /// Only the owner can grant the `Admin` and `Minter` roles,
+    /// other roles can also be granted by admins.
+    fn grant_role(&mut self, role: Role, account_id: AccountId);
+
+    /// Revokes `role` from `account_id`.
+    /// Only the owner can revoke the `Admin` and `Minter` roles,
+    /// other roles can also be revoked by admins.
+    fn revoke_role(&mut self, role: Role, account_id: AccountId);
+
+    /// Removes `role` from the predecessor account.
+    fn renounce_role(&mut self, role: Role);
+
+    /// Returns whether `account_id` holds `role`.
+    /// This is true for the contract owner for all roles except for `Minter`.
+    fn has_role(&self, role: Role, account_id: AccountId) -> bool;
+
+    /// Returns all accounts that have explicitly been granted `role`.
+    fn role_members(&self, role: Role) -> Vec<AccountId>;
+}
+
+#[near_bindgen]
+impl RoleManagement for Contract {
+    fn grant_role(&mut self, role: Role, account_id: AccountId) {
+        self.assert_role_admin(role);
+        self.internal_grant_role(role, &account_id);
+    }
+
+    fn revoke_role(&mut self, role: Role, account_id: AccountId) {
+        self.assert_role_admin(role);
+        self.internal_remove_role(role, &account_id);
+    }
+
+    fn renounce_role(&mut self, role: Role) {
+        self.internal_remove_role(role, &env::predecessor_account_id());
+    }
+
+    fn has_role(&self, role: Role, account_id: AccountId) -> bool {
+        self.internal_has_role(role, &account_id)
+    }
+
+    fn role_members(&self, role: Role) -> Vec<AccountId> {
+        if role == Role::Minter {
+            return self.minters.keys().collect();
+        }
+        self.roles.get(&role).unwrap_or_default()
+    }
+}
+
+impl Contract {
+    pub(crate) fn internal_has_role(&self, role: Role, account_id: &AccountId) -> bool {
+        (account_id == &self.owner && role != Role::Minter)
+            || self.internal_is_role_member(role, account_id)
+    }
+
+    /// Whether `role` has explicitly been granted to `account_id`.
+    pub(crate) fn internal_is_role_member(&self, role: Role, account_id: &AccountId) -> bool {
+        if role == Role::Minter {
+            return self.minters.get(account_id).is_some();
+        }
+        self.roles
+            .get(&role)
+            .map(|members| members.contains(account_id))
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn internal_grant_role(&mut self, role: Role, account_id: &AccountId) {
+        require!(
+            !self.internal_is_role_member(role, account_id),
+            "Account already has role"
+        );
+        if role == Role::Minter {
+            self.minters.insert(account_id, &MinterInfo::default());
+        } else {
+            let mut members = self.roles.get(&role).unwrap_or_default();
+            members.push(account_id.clone());
+            self.roles.insert(&role, &members);
+        }
+
+        RoleGranted {
+            role,
+            account_id,
+            by: &env::predecessor_account_id(),
+        }
+        .emit();
+    }
+
+    pub(crate) fn assert_role(&self, role: Role) {
+        require!(
+            self.internal_has_role(role, &env::predecessor_account_id()),
+            format!("Only accounts with role {:?} can call this function", role)
+        );
+    }
+
+    fn assert_role_admin(&self, role: Role) {
+        if role == Role::Admin || role == Role::Minter {
+            self.assert_owner();
+        } else {
+            self.assert_role(Role::Admin);
+        }
+    }
+
+    pub(crate) fn internal_remove_role(&mut self, role: Role, account_id: &AccountId) {
+        require!(
+            self.internal_is_role_member(role, account_id),
+            "Account does not have role"
+        );
+        if role == Role::Minter {
+            self.minters.remove(account_id);
+        } else {
+            let mut members = self.roles.get(&role).unwrap_or_default();
+            members.retain(|member| member != account_id);
+            self.roles.insert(&role, &members);
+        }
+
+        RoleRevoked {
+            role,
+            account_id,
+            by: &env::predecessor_account_id(),
+        }
+        .emit();
+    }
+}
@@ -1,48 +1,239 @@
-use crate::{Contract, ContractExt};
+use crate::{event::StorageSponsorshipBudgetUpdate, pause::PauseCategory, Contract, ContractExt};
 use near_contract_standards::{
     fungible_token::events::FtBurn,
     storage_management::{StorageBalance, StorageBalanceBounds, StorageManagement},
 };
-use near_sdk::{near_bindgen, AccountId, NearToken};
+use near_sdk::{
+    assert_one_yocto,
+    borsh::{BorshDeserialize, BorshSerialize},
+    env,
+    json_types::U64,
+    log, near_bindgen, require,
+    serde::{Deserialize, Serialize},
+    AccountId, NearToken, Promise,
+};
+
+/// Size of the `storage_payers` entry for the longest possible account IDs,
+/// including the overhead of a storage record.
+const MAX_STORAGE_PAYER_BYTES: u64 = 40 + (1 + 4 + 64) + (4 + 64);
+
+/// Accounting of storage registrations that have been paid for by the contract itself.
+/// Sponsorship is limited by `max_count` and `max_amount`, without them it is unlimited.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Default, Debug)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct StorageSponsorship {
+    pub sponsored_count: u64,
+    pub sponsored_amount: NearToken,
+    pub max_count: Option<u64>,
+    pub max_amount: Option<NearToken>,
+}
+
+impl StorageSponsorship {
+    fn remaining_count(&self) -> Option<u64> {
+        self.max_count
+            .map(|max_count| max_count.saturating_sub(self.sponsored_count))
+    }
+
+    fn remaining_amount(&self) -> Option<NearToken> {
+        self.max_amount
+            .map(|max_amount| max_amount.saturating_sub(self.sponsored_amount))
+    }
+
+    /// Whether the remaining budget covers another registration costing `cost`.
+    fn covers(&self, cost: NearToken) -> bool {
+        self.remaining_count().map_or(true, |count| count > 0)
+            && self
+                .remaining_amount()
+                .map_or(true, |amount| amount >= cost)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageSponsorshipView {
+    /// Number of accounts that have been registered at the expense of the contract.
+    pub sponsored_count: U64,
+    /// Total amount of NEAR that has been spent on these registrations.
+    pub sponsored_amount: NearToken,
+    pub max_count: Option<U64>,
+    pub max_amount: Option<NearToken>,
+    pub remaining_count: Option<U64>,
+    pub remaining_amount: Option<NearToken>,
+}
 
 #[near_bindgen]
 impl StorageManagement for Contract {
+    /// Registers `account_id` and remembers the predecessor as payer of the registration,
+    /// which will receive the refund on `storage_unregister`.
+    #[allow(unused_variables)]
     #[payable]
     fn storage_deposit(
         &mut self,
         account_id: Option<AccountId>,
         registration_only: Option<bool>,
     ) -> StorageBalance {
-        self.token.storage_deposit(account_id, registration_only)
+        let amount = env::attached_deposit();
+        let predecessor_id = env::predecessor_account_id();
+        let account_id = account_id.unwrap_or_else(|| predecessor_id.clone());
+        if self.token.accounts.contains_key(&account_id) {
+            log!("The account is already registered, refunding the deposit");
+            if !amount.is_zero() {
+                Promise::new(predecessor_id).transfer(amount);
+            }
+        } else {
+            let min_balance = self.internal_min_storage_balance();
+            require!(
+                amount >= min_balance,
+                "The attached deposit is less than the minimum storage balance"
+            );
+
+            self.token.internal_register_account(&account_id);
+            self.storage_payers.insert(&account_id, &predecessor_id);
+            let refund = amount.saturating_sub(min_balance);
+            if !refund.is_zero() {
+                Promise::new(predecessor_id).transfer(refund);
+            }
+        }
+        self.storage_balance_of(account_id).unwrap()
     }
 
     #[payable]
     fn storage_withdraw(&mut self, amount: Option<NearToken>) -> StorageBalance {
-        self.token.storage_withdraw(amount)
+        self.assert_not_paused(PauseCategory::Storage);
+        self.token.storage_withdraw(amount);
+        self.storage_balance_of(env::predecessor_account_id())
+            .unwrap()
     }
 
+    /// Unregisters the predecessor account. The storage deposit is refunded to whoever paid
+    /// for the registration. It is withheld for registrations that have been sponsored
+    /// by the contract, which includes all accounts that have been registered before
+    /// payers have been tracked.
     #[payable]
     fn storage_unregister(&mut self, force: Option<bool>) -> bool {
-        if let Some((account_id, balance)) = self.token.internal_storage_unregister(force) {
-            if balance > 0 {
-                FtBurn {
-                    owner_id: &account_id,
-                    amount: balance.into(),
-                    memo: None,
-                }
-                .emit();
+        self.assert_not_paused(PauseCategory::Storage);
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let Some(balance) = self.token.accounts.get(&account_id) else {
+            log!("The account {} is not registered", &account_id);
+            return false;
+        };
+        require!(
+            balance == 0 || force.unwrap_or_default(),
+            "Can't unregister the account with the positive balance without force"
+        );
+        self.token.accounts.remove(&account_id);
+        self.locks.remove(&account_id);
+        self.token.total_supply -= balance;
+        if let Some(payer_id) = self.storage_payers.remove(&account_id) {
+            Promise::new(payer_id).transfer(self.internal_min_storage_balance());
+        }
+
+        if balance > 0 {
+            FtBurn {
+                owner_id: &account_id,
+                amount: balance.into(),
+                memo: None,
             }
-            true
-        } else {
-            false
+            .emit();
         }
+        true
     }
 
     fn storage_balance_bounds(&self) -> StorageBalanceBounds {
-        self.token.storage_balance_bounds()
+        let min = self.internal_min_storage_balance();
+        StorageBalanceBounds {
+            min,
+            max: Some(min),
+        }
     }
 
     fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
-        self.token.storage_balance_of(account_id)
+        self.token
+            .accounts
+            .contains_key(&account_id)
+            .then(|| StorageBalance {
+                total: self.internal_min_storage_balance(),
+                available: NearToken::from_yoctonear(0),
+            })
+    }
+}
+
+pub trait StorageSponsorshipManagement {
+    fn storage_sponsorship(&self) -> StorageSponsorshipView;
+
+    /// Limits the number of accounts and the total amount of NEAR the contract spends on
+    /// registering accounts for `mint`. Once the budget is used up, accounts need to be
+    /// registered via `storage_deposit` before tokens can be minted to them.
+    /// Can only be called by the owner.
+    fn set_storage_sponsorship_budget(
+        &mut self,
+        max_count: Option<U64>,
+        max_amount: Option<NearToken>,
+    );
+}
+
+#[near_bindgen]
+impl StorageSponsorshipManagement for Contract {
+    fn storage_sponsorship(&self) -> StorageSponsorshipView {
+        StorageSponsorshipView {
+            sponsored_count: self.storage_sponsorship.sponsored_count.into(),
+            sponsored_amount: self.storage_sponsorship.sponsored_amount,
+            max_count: self.storage_sponsorship.max_count.map(Into::into),
+            max_amount: self.storage_sponsorship.max_amount,
+            remaining_count: self.storage_sponsorship.remaining_count().map(Into::into),
+            remaining_amount: self.storage_sponsorship.remaining_amount(),
+        }
+    }
+
+    fn set_storage_sponsorship_budget(
+        &mut self,
+        max_count: Option<U64>,
+        max_amount: Option<NearToken>,
+    ) {
+        self.assert_owner();
+        self.storage_sponsorship.max_count = max_count.map(|max_count| max_count.0);
+        self.storage_sponsorship.max_amount = max_amount;
+
+        StorageSponsorshipBudgetUpdate {
+            max_count,
+            max_amount,
+        }
+        .emit();
+    }
+}
+
+impl Contract {
+    /// Registers `account_id` at the expense of the contract.
+    /// Panics if the sponsorship budget does not cover the registration.
+    pub(crate) fn internal_register_sponsored_account(&mut self, account_id: &AccountId) {
+        self.internal_assert_sponsorship_covers(account_id);
+        let cost = self.internal_min_storage_balance();
+        let sponsorship = &mut self.storage_sponsorship;
+        sponsorship.sponsored_count += 1;
+        sponsorship.sponsored_amount = sponsorship.sponsored_amount.saturating_add(cost);
+        self.token.internal_register_account(account_id);
+    }
+
+    /// Panics if the sponsorship budget does not cover registering `account_id`.
+    pub(crate) fn internal_assert_sponsorship_covers(&self, account_id: &AccountId) {
+        require!(
+            self.storage_sponsorship
+                .covers(self.internal_min_storage_balance()),
+            format!(
+                "Storage sponsorship budget is exhausted, {} has to be registered via storage_deposit",
+                account_id
+            )
+        );
+    }
+
+    /// Storage balance of a registration, which covers the account entry of the token
+    /// and the entry of the payer, who gets it refunded on `storage_unregister`.
+    /// Sponsored registrations are accounted with the same amount.
+    pub(crate) fn internal_min_storage_balance(&self) -> NearToken {
+        self.token
+            .storage_balance_bounds()
+            .min
+            .saturating_add(env::storage_byte_cost().saturating_mul(MAX_STORAGE_PAYER_BYTES.into()))
     }
 }
@@ -23,6 +23,7 @@ contract ShitzuMigrate is AccessControl {
     IERC20 public shitzuAurora;
     string public shitzuNearId;
     NEAR public near;
+    uint64 public migrationNonce;
 
     constructor(
         IERC20 _wNEAR,
@@ -57,16 +58,17 @@ contract ShitzuMigrate is AccessControl {
     function migrate(string memory accountId, uint128 amount) public {
         shitzuAurora.transferFrom(msg.sender, address(this), amount);
 
+        // Borsh encoded `MintArgs`, so that the account ID can not alter the arguments
         bytes memory data = abi.encodePacked(
-            '{"account_id": "',
-            accountId,
-            '", "amount": "',
-            Strings.toString(amount),
-            '"}'
+            bytes(accountId).encode(),
+            Codec.encodeU128(amount),
+            Codec.encodeU64(migrationNonce),
+            bytes20(msg.sender)
         );
+        migrationNonce++;
         PromiseCreateArgs memory callMint = near.call(
             shitzuNearId,
-            "mint",
+            "mint_borsh",
             data,
             0,
             MIGRATE_NEAR_GAS
@@ -85,6 +87,11 @@ contract ShitzuMigrate is AccessControl {
         callMint.then(callback).transact();
     }
 
+    // Migrates to the ETH-implicit NEAR account of the sender, which is controlled by the same key
+    function migrateToEthImplicit(uint128 amount) public {
+        migrate(Strings.toHexString(uint256(uint160(msg.sender)), 20), amount);
+    }
+
     function migrateCallback(
         address sender,
         uint128 amount